@@ -7,9 +7,52 @@ export interface SigningCommitments {
   hiding: string
   binding: string
 }
+export interface ThresholdAndTotal {
+  threshold: number
+  total: number
+}
 export function roundOne(keyPackage: string, seed: number): SigningCommitments
 export function roundTwo(signingPackage: string, keyPackage: string, publicKeyRandomness: string, seed: number): string
+/**
+ * Serialize a map of signer identifiers to signing commitments into a compact
+ * binary blob: a little-endian u32 count, followed by each entry's 32 byte
+ * identifier, hiding commitment, and binding commitment. Identifiers and
+ * commitments are validated, and entries are sorted by identifier, so equal
+ * maps always serialize to the same bytes.
+ */
+export function serializeCommitments(commitments: Record<string, SigningCommitments>): Buffer
+/** Deserialize a commitments map written by `serialize_commitments`. */
+export function deserializeCommitments(jsBytes: Buffer): Record<string, SigningCommitments>
+/**
+ * Serialize a map of signer identifiers to signature shares, both hex
+ * encoded, into a binary blob: a little-endian u32 count, followed by each
+ * entry's 32 byte identifier and 32 byte signature share. Identifiers and
+ * shares are validated, and entries are sorted by identifier.
+ */
+export function serializeSignatureShares(shares: Record<string, string>): Buffer
+/**
+ * Deserialize a signature shares map written by
+ * `serialize_signature_shares`, validating each identifier and share.
+ */
+export function deserializeSignatureShares(jsBytes: Buffer): Record<string, string>
 export function splitSecret(coordinatorSaplingKey: string, minSigners: number, maxSigners: number, identifiers: Array<string>): TrustedDealerKeyPackages
+/**
+ * The group verifying key of a public key package. Combined with the
+ * transaction's `public_key_randomness`, this gives the randomized key that
+ * the aggregated FROST signature is checked against.
+ */
+export function groupPublicKey(publicKeyPackage: string): string
+/**
+ * The number of signers required to sign, and the total number of signers,
+ * for a FROST account. A public key package does not record the signing
+ * threshold, so it is read from the key package of any of the signers.
+ */
+export function thresholdAndTotal(publicKeyPackage: string, keyPackage: string): ThresholdAndTotal
+/**
+ * The hex encoded identifiers of every participant in a public key package,
+ * sorted.
+ */
+export function publicKeyPackageIdentifiers(publicKeyPackageStr: string): Array<string>
 export function contribute(inputPath: string, outputPath: string, seed?: string | undefined | null): Promise<string>
 export function verifyTransform(paramsPath: string, newParamsPath: string): Promise<string>
 export const KEY_LENGTH: number
@@ -39,6 +82,30 @@ export const NOTE_ENCRYPTION_KEY_LENGTH: number
 export const MAC_LENGTH: number
 export const ENCRYPTED_NOTE_PLAINTEXT_LENGTH: number
 export const ENCRYPTED_NOTE_LENGTH: number
+/**
+ * The outcome of decrypting a note. `status` is one of:
+ *
+ * - "owned": the note decrypted with the key and `note` holds its bytes.
+ * - "notOwned": the key does not decrypt the note.
+ * - "corrupted": the key decrypts the note, but the plaintext is malformed
+ *   or does not match the note commitment.
+ *
+ * The encryption cannot tell a foreign key from a tampered ciphertext, since
+ * both fail authentication, so ciphertext corrupted in transit is reported as
+ * "notOwned". Only tampering that still authenticates is "corrupted".
+ */
+export interface NativeDecryptionResult {
+  status: string
+  note?: Buffer
+}
+/**
+ * Decrypt only the value of an encrypted note with the owner's incoming view
+ * key. The value is not checked against the note commitment, so this is meant
+ * for balance scans rather than for notes about to be spent.
+ *
+ * Returns undefined if the note was unable to be decrypted with the given key.
+ */
+export function decryptValue(encryptedNote: Buffer, incomingHexKey: string): bigint | null
 export const PUBLIC_ADDRESS_LENGTH: number
 export const RANDOMNESS_LENGTH: number
 export const MEMO_LENGTH: number
@@ -49,13 +116,190 @@ export interface NativeSpendDescription {
   rootHash: Buffer
   nullifier: Buffer
 }
+/**
+ * Build a spend description from its parts, such as when loading one from
+ * storage to compare with `TransactionPosted.getSpend`. The root hash and
+ * nullifier must be 32 bytes each.
+ */
+export function spendDescriptionFromParts(treeSize: number, rootHash: Buffer, nullifier: Buffer): NativeSpendDescription
+export function serializeSpendDescription(spend: NativeSpendDescription): Buffer
+export function deserializeSpendDescription(bytes: Buffer): NativeSpendDescription
 export const PROOF_LENGTH: number
 export const TRANSACTION_SIGNATURE_LENGTH: number
 export const TRANSACTION_PUBLIC_KEY_RANDOMNESS_LENGTH: number
 export const TRANSACTION_EXPIRATION_LENGTH: number
 export const TRANSACTION_FEE_LENGTH: number
 export const LATEST_TRANSACTION_VERSION: number
+export interface NativeTransactionRecipient {
+  address: string
+  value: bigint
+  assetId: Buffer
+}
+export interface NativeOutputPosition {
+  commitment: Buffer
+  position: bigint
+}
+export interface NativeTrackedPost {
+  transaction: Buffer
+  changeIndex: number
+}
+export interface NativeTreeSizeRange {
+  min: number
+  max: number
+}
+export interface NativeTransactionSummary {
+  version: number
+  fee: bigint
+  expiration: number
+  spendsCount: number
+  outputsCount: number
+  mintsCount: number
+  burnsCount: number
+  nullifiers: Array<Buffer>
+  outputCommitments: Array<Buffer>
+  assetIds: Array<Buffer>
+}
+export interface NativeVerificationTimings {
+  ok: boolean
+  parseMs: number
+  proofMs: number
+  bindingMs: number
+}
+export interface NativeSigningSession {
+  transaction: Buffer
+  commitments: Record<string, SigningCommitments>
+  signatureShares: Record<string, string>
+}
+export interface NativeAssetEffect {
+  minted: bigint
+  burned: bigint
+  net: bigint
+}
 export function verifyTransactions(serializedTransactions: Array<Buffer>): boolean
+/**
+ * Verify the serialized transactions like `verifyTransactions`, also
+ * returning the milliseconds spent in each phase: deserializing, checking
+ * the signatures (including the binding signature), and batch verifying the
+ * proofs. Verification stops at the first failing phase, and phases that
+ * did not run report 0.
+ */
+export function verifyTransactionsTimed(serializedTransactions: Array<Buffer>): NativeVerificationTimings
+/**
+ * Verify the serialized transactions in chunks of `chunk_size`, so that only
+ * one chunk of deserialized transactions is held in memory at a time.
+ *
+ * Returns the index of the first transaction that could not be deserialized
+ * or failed verification, or null if every transaction is valid.
+ */
+export function verifyTransactionsStreaming(serializedTransactions: Array<Buffer>, chunkSize: number): number | null
+/**
+ * The hex public addresses of the owners of every mint across the serialized
+ * transactions, such as all the transactions in a block. Each address is
+ * listed once, in the order it was first seen.
+ */
+export function mintOwners(serializedTransactions: Array<Buffer>): Array<string>
+/**
+ * Build a child transaction that spends the native asset change note of
+ * `original`, pays `additional_fee` to the miner, and returns the remainder
+ * to the spender.
+ *
+ * This assumes that the change of `original` went to the spender's own
+ * address, and that the change note has already been added to the note tree.
+ * Notes can only be spent with a witness against a known anchor, so
+ * `change_witness` must be a witness for that change note, and the child
+ * cannot be mined before `original` is.
+ */
+export function bumpFee(original: Buffer, additionalFee: bigint, spenderHexKey: string, changeWitness: object): Buffer
+/**
+ * The sum of the fees paid by the serialized transactions, i.e. the fees a
+ * block producer collects. Miners fee transactions, which have a negative
+ * fee, are skipped.
+ */
+export function totalFees(serializedTransactions: Array<Buffer>): bigint
+/**
+ * Returns true if the buffer parses as exactly one serialized transaction.
+ * Only the structure is checked; proofs and signatures are not verified.
+ */
+export function isValidTransactionEncoding(jsBytes: Buffer): boolean
+/**
+ * Returns true if the buffer is the canonical encoding of a transaction, i.e.
+ * it parses and serializes back to exactly the same bytes. Encodings that
+ * fail to parse are not canonical.
+ */
+export function isCanonical(serialized: Buffer): boolean
+/**
+ * Classify a serialized transaction as "posted" if it is a fully signed
+ * transaction or "unsigned" if it still needs to be signed, such as with
+ * FROST. Throws if the buffer is neither.
+ */
+export function transactionKind(buffer: Buffer): string
+/**
+ * The id of the serialized transaction, i.e. its signature hash. Equivalent to
+ * `new TransactionPosted(serialized).hash()`.
+ */
+export function transactionId(serializedTransaction: Buffer): Buffer
+/**
+ * The smallest fee that pays at least `rate` per byte of the serialized
+ * transaction. The fee is a fixed size field, so setting it does not by
+ * itself change the size of the transaction.
+ */
+export function minFeeForRate(serializedTransaction: Buffer, rate: bigint): bigint
+/**
+ * Returns true if the binding signature of the serialized transaction is valid
+ * under the given verifying key.
+ */
+export function verifySignature(serializedTransaction: Buffer, verifyingKey: Buffer): boolean
+/**
+ * Returns true if the serialized transaction carries a valid FROST signature
+ * from the group in the public key package. `public_key_randomness` is the
+ * hex randomness of the unsigned transaction that was signed, used to
+ * reconstruct the randomized group verifying key. Proofs are not verified.
+ */
+export function verifyFrostSignature(serializedTransaction: Buffer, publicKeyPackageStr: string, publicKeyRandomness: string): boolean
+/**
+ * Map the hex identifier of every asset minted or burned by either serialized
+ * transaction to the change in its effects when `b` replaces `a`: the
+ * difference in amount minted, in amount burned, and in net supply change
+ * (minted minus burned). Each difference is `b`'s value minus `a`'s, and
+ * assets whose effects are the same in both are omitted.
+ */
+export function assetEffectDiff(a: Buffer, b: Buffer): Record<string, NativeAssetEffect>
+/**
+ * Build and post a transaction that spends every given note to a single
+ * output for `destination`, returning the serialized posted transaction.
+ * Notes are passed serialized, each with the witness at the same index, and
+ * must all be for `asset_id`. The fee is taken out of the swept value, so
+ * only sweeps of the native asset can pay a non-zero fee.
+ */
+export function sweep(notes: Array<Buffer>, witnesses: Array<object>, assetId: Buffer, destination: string, spenderHexKey: string, intendedTransactionFee: bigint): Buffer
+/**
+ * Returns the indices of the serialized transactions that have expired as of
+ * `current_sequence`. Only the expiration field of each transaction is read.
+ * An expiration of 0 means the transaction never expires.
+ */
+export function filterExpired(serializedTransactions: Array<Buffer>, currentSequence: number): Array<number>
+/**
+ * Returns true if the two serialized transactions spend at least one of the
+ * same notes, i.e. they share a nullifier and cannot both be mined.
+ */
+export function transactionsConflict(a: Buffer, b: Buffer): boolean
+/**
+ * Returns true if the two proposed transactions have the same spends,
+ * outputs, mints, burns, and expiration, ignoring the randomness chosen for
+ * each proof and output note.
+ */
+export function proposedEquivalent(a: Transaction, b: Transaction): boolean
+/**
+ * The tree position of the note a witness is for, implied by the left and
+ * right sides of the nodes in its auth path.
+ */
+export function witnessPosition(witness: object): bigint
+/**
+ * Compute witnesses for the notes at the given positions of a note commitment
+ * tree snapshot. The snapshot is the 32 byte commitment of every note in the
+ * tree, concatenated in position order.
+ */
+export function witnessesForPositions(tree: Buffer, positions: Array<bigint>): Array<NativeWitness>
 export interface IdentiferKeyPackage {
   identifier: string
   keyPackage: string
@@ -93,6 +337,17 @@ export function spendingKeyToWords(privateKey: string, languageCode: LanguageCod
 export function wordsToSpendingKey(words: string, languageCode: LanguageCode): string
 export function generateKeyFromPrivateKey(privateKey: string): Key
 export function initializeSapling(): void
+/**
+ * The smallest note commitment tree size that includes notes at all of the
+ * given positions, i.e. the largest position plus one.
+ */
+export function requiredTreeSize(positions: Array<bigint>): number
+/**
+ * Returns true if the proof generation key and view key belong to the same
+ * account. Building a transaction with mismatched keys produces an unsigned
+ * transaction that cannot be signed correctly.
+ */
+export function keysConsistent(proofGenerationKey: string, viewKey: string): boolean
 export function isValidPublicAddress(hexAddress: string): boolean
 export class FishHashContext {
   constructor(full: boolean)
@@ -125,12 +380,28 @@ export class RollingFilter {
 export type NativeAsset = Asset
 export class Asset {
   constructor(creatorPublicAddress: string, name: string, metadata: string)
+  /**
+   * Rebuild an asset from its creator, name, and metadata, failing if the
+   * derived identifier does not match `expected_id`.
+   */
+  static fromParts(creatorPublicAddress: string, name: string, metadata: string, expectedId: Buffer): NativeAsset
   metadata(): Buffer
   name(): Buffer
   nonce(): number
   creator(): Buffer
+  /** The asset name as a string, without the zero byte padding */
+  nameString(): string
+  /** The asset metadata as a string, without the zero byte padding */
+  metadataString(): string
+  /** The public address of the asset creator, in hex */
+  creatorHex(): string
   static nativeId(): Buffer
   id(): Buffer
+  /**
+   * Whether the asset identifier is the one derived from the creator,
+   * name, metadata, and nonce.
+   */
+  verify(): boolean
   serialize(): Buffer
   static deserialize(jsBytes: Buffer): NativeAsset
 }
@@ -153,6 +424,18 @@ export class NoteEncrypted {
   decryptNoteForOwner(incomingHexKey: string): Buffer | null
   /** Returns undefined if the note was unable to be decrypted with the given key. */
   decryptNoteForSpender(outgoingHexKey: string): Buffer | null
+  /**
+   * Decrypt the note like `decryptNoteForOwner`, reporting whether the key
+   * does not own the note or the note is corrupted rather than returning
+   * undefined for both.
+   */
+  tryDecryptNoteForOwner(incomingHexKey: string): NativeDecryptionResult
+  /**
+   * Decrypt the note like `decryptNoteForSpender`, reporting whether the
+   * key did not send the note or the note is corrupted rather than
+   * returning undefined for both.
+   */
+  tryDecryptNoteForSpender(outgoingHexKey: string): NativeDecryptionResult
 }
 export type NativeNote = Note
 export class Note {
@@ -164,6 +447,12 @@ export class Note {
    * This hash is what gets used for the leaf nodes in a Merkle Tree.
    */
   hash(): Buffer
+  /**
+   * The commitment of the note, which is the same as `hash`. It depends
+   * only on the note's contents, so it is stable across serialization and
+   * identifies the note regardless of the transaction it appears in.
+   */
+  commitment(): Buffer
   /** Value this note represents. */
   value(): bigint
   /**
@@ -187,31 +476,293 @@ export class Note {
    * 'nullifier set', preventing double-spend.
    */
   nullifier(ownerViewKey: string, position: bigint): Buffer
+  /**
+   * Create a brand-new note owned by `address`, carrying the same asset,
+   * value, and memo as this one, sent by the owner of `spender_hex_key`.
+   *
+   * This is not a re-encryption of this note: the new note has fresh
+   * randomness and its own commitment, and nothing on chain links the two.
+   * Fails unless the spender owns this note.
+   */
+  reissueTo(address: string, spenderHexKey: string): NativeNote
 }
 export type NativeTransactionPosted = TransactionPosted
 export class TransactionPosted {
   constructor(jsBytes: Buffer)
   serialize(): Buffer
+  /**
+   * The transaction's public fields in a single object: version, fee,
+   * expiration, description counts, the nullifiers of the spends, the note
+   * commitments of the outputs, and the distinct identifiers of the assets
+   * minted or burned, in order of first appearance.
+   */
+  summary(): NativeTransactionSummary
+  /**
+   * A compact record of the transaction's public metadata for archival
+   * indexes, without proofs, encrypted notes, or signatures. It cannot be
+   * deserialized into a transaction or verified.
+   *
+   * All integers are little-endian. The record holds the version byte, the
+   * fee (i64), and the expiration (u32), followed by spend, output, mint,
+   * and burn sections, each starting with a u32 count of its entries:
+   * spends are a nullifier, tree size (u32), and root hash; outputs are a
+   * note commitment; mints and burns are an asset identifier and value
+   * (u64). Hashes and identifiers are 32 bytes.
+   */
+  serializeMetadataOnly(): Buffer
+  version(): number
   notesLength(): number
   getNote(index: number): Buffer
+  /**
+   * The serialized merkle notes of every output, in output order. Same as
+   * calling `getNote` for each index.
+   */
+  allEncryptedNotes(): Array<Buffer>
+  /**
+   * Whether the note's commitment matches the commitment of one of the
+   * outputs, i.e. the note was created by this transaction.
+   */
+  containsOutput(note: Note): boolean
+  /**
+   * The outgoing cipher text of the output at the given index, which the
+   * sender can decrypt with their outgoing view key to recover the note.
+   */
+  outputOutgoingCiphertext(index: number): Buffer
+  /**
+   * Whether the output at the given index decrypts with the given incoming
+   * view key to a note of the expected value. False if the output cannot
+   * be decrypted with the key.
+   */
+  verifyOutputClaim(index: number, expectedValue: bigint, incomingViewKeyHex: string): boolean
+  /**
+   * The identifiers of the assets of every output that can be decrypted
+   * with the given incoming view key, each listed once in output order.
+   */
+  visibleAssets(incomingViewKeyHex: string): Array<Buffer>
+  /**
+   * The note commitment of each output and the note tree position it takes
+   * when the transaction's outputs are appended to the tree starting at
+   * `start_position`. Outputs occupy consecutive positions, in order.
+   */
+  assignOutputPositions(startPosition: bigint): Array<NativeOutputPosition>
+  /**
+   * The recipients of every output that can be decrypted with the given
+   * outgoing view key, i.e. the outputs sent by the owner of that key.
+   */
+  recipients(outgoingHexKey: string): Array<NativeTransactionRecipient>
+  /**
+   * The number of unique recipient addresses among the outputs that can be
+   * decrypted with the given outgoing view key.
+   */
+  distinctRecipients(outgoingHexKey: string): number
+  /**
+   * Map the hex identifier of every asset sent by this transaction to the
+   * total value sent, excluding outputs to the change address. Only
+   * outputs that can be decrypted with the given outgoing view key are
+   * counted.
+   */
+  outputTotals(outgoingHexKey: string, changeAddress: string): Record<string, bigint>
+  /**
+   * The total value of the outputs of the given asset that can be decrypted
+   * with the given outgoing view key, including any change.
+   */
+  outputValueForAsset(assetId: Buffer, outgoingHexKey: string): bigint
+  /**
+   * Whether two outputs that can be decrypted with the given outgoing view
+   * key are sent to the same address. Change counts as an output to the
+   * change address, so a payment to the change address also counts.
+   */
+  hasAddressReuse(outgoingHexKey: string): boolean
+  /**
+   * Whether any output that can be decrypted with the given outgoing view
+   * key has a value below `threshold`. Output values are encrypted, so
+   * outputs the key cannot decrypt are not counted, even if they are dust.
+   */
+  hasDustOutputs(threshold: bigint, outgoingHexKey: string): boolean
+  /**
+   * Whether this transaction moves funds within a single account, such as
+   * when consolidating notes: it spends at least one note, and every
+   * output is sent by and to the account of the given view key. Any output
+   * the account can't decrypt goes elsewhere.
+   */
+  isSelfTransfer(viewKeyHex: string): boolean
   spendsLength(): number
+  /**
+   * The total number of spend, output, mint, and burn descriptions in the
+   * transaction.
+   */
+  descriptionCount(): number
   getSpend(index: number): NativeSpendDescription
+  /**
+   * The smallest and largest note commitment tree sizes among the spends'
+   * anchors, or null if the transaction has no spends.
+   */
+  spendTreeSizeRange(): NativeTreeSizeRange | null
+  /**
+   * The key that verifies the authorization signature of the spend at the
+   * given index. Every spend in a transaction is signed under the
+   * transaction's randomized public key, so this is the same for all spends.
+   */
+  spendVerifyingKey(index: number): Buffer
   fee(): bigint
+  /**
+   * The fee paid per byte of the serialized transaction, which is what
+   * block assemblers sort the mempool by.
+   */
+  feeRate(): number
+  /** Whether the transaction mints the asset with the given identifier. */
+  hasMintFor(assetId: Buffer): boolean
+  /** Whether the transaction burns the asset with the given identifier. */
+  hasBurnFor(assetId: Buffer): boolean
+  /**
+   * The serialized asset committed in the mint at the given index, exactly
+   * as it appears in the transaction.
+   */
+  mintAssetBytes(index: number): Buffer
+  /**
+   * The public inputs the Groth16 verifier checks the proof of the spend at
+   * the given index against, in circuit order, each as a 32 byte little
+   * endian field element.
+   */
+  spendPublicInputs(index: number): Array<Buffer>
+  /**
+   * The public inputs the Groth16 verifier checks the proof of the output
+   * at the given index against, in circuit order, each as a 32 byte little
+   * endian field element.
+   */
+  outputPublicInputs(index: number): Array<Buffer>
+  /**
+   * Serializes the binding signature on every call. Prefer
+   * `bindingSignatureBytes`, which returns the same bytes without
+   * reserializing.
+   */
   transactionSignature(): Buffer
+  /**
+   * The binding signature bytes, serialized once when the transaction was
+   * read.
+   */
+  bindingSignatureBytes(): Buffer
   hash(): Buffer
+  /**
+   * A short identifier for logging: the first 8 bytes of the transaction
+   * hash, in hex.
+   */
+  fingerprint(): string
   expiration(): number
+  /**
+   * Approximate number of bytes this transaction occupies in memory,
+   * including its proofs and descriptions.
+   */
+  memoryFootprint(): number
+  /**
+   * Every proof in the transaction and its public inputs, laid out in a
+   * single buffer. The buffer starts with six little-endian u32s: the
+   * number of spends, outputs, and mints, then the byte offsets of the
+   * spend, output, and mint sections. Each section holds its proofs
+   * followed by their public inputs as 32 byte little-endian scalars (7 per
+   * spend, 7 per output, 4 per mint).
+   */
+  exportProofBundle(): Buffer
+  /**
+   * A heuristic score from 0 to 100 of how little the transaction reveals
+   * from its public shape. Outputs that conceal change and decoy outputs
+   * raise the score; many spends, mints, and burns lower it.
+   */
+  privacyScore(): number
+  /**
+   * A hash over the sorted nullifiers of the transaction's spends, which
+   * identifies the set of notes the transaction spends.
+   */
+  nullifierSetHash(): Buffer
+  /**
+   * The serialized transaction fields that are hashed to produce the
+   * transaction signature hash returned by `hash`.
+   */
+  signatureHashPreimage(): Buffer
 }
 export type NativeTransaction = Transaction
 export class Transaction {
   constructor(version: number)
+  /**
+   * Restrict the assets this transaction can output, mint, or burn to the
+   * given asset identifiers. Applies to calls made after it is set.
+   */
+  setAllowedAssets(assetIds: Array<Buffer>): void
+  /**
+   * Choose the note commitment tree size of the anchor this transaction is
+   * built against. Spends must use a witness whose tree size is no larger
+   * than the anchor's, otherwise they fail with an anchor mismatch.
+   */
+  setAnchorTreeSize(treeSize: number): void
+  /**
+   * Choose the note commitment tree root this transaction is built against.
+   * Spends must use a witness to this root, otherwise they fail with an
+   * anchor mismatch.
+   */
+  setAnchor(root: Buffer): void
+  /**
+   * Limit the number of memo bytes that outputs added after this call may
+   * use. Outputs with longer memos are rejected.
+   */
+  setMaxMemoBytes(maxMemoBytes: number): void
   /** Create a proof of a new note owned by the recipient in this transaction. */
   output(note: Note): void
+  /**
+   * Create a proof of a new note from its serialized bytes, as produced by
+   * `Note.serialize()`, without constructing a `Note` first.
+   */
+  outputSerialized(noteBytes: Buffer): void
+  /**
+   * Pad the transaction with zero value outputs to `spender_address` until
+   * it has `target_output_count` outputs. Decoys are owned by the spender,
+   * but each one increases the transaction size and therefore its fee.
+   * Change is added after the decoys when posting.
+   */
+  addDecoyOutputs(spenderAddress: string, targetOutputCount: number): void
   /** Spend the note owned by spender_hex_key at the given witness location. */
   spend(note: Note, witness: object): void
+  /**
+   * Spend the note using a witness built on the Rust side with
+   * `NativeWitness.fromCompressed`.
+   */
+  spendCompressed(note: Note, witness: NativeWitness): void
+  /**
+   * Not supported: always throws. Every spend in a transaction is
+   * authorized by the single key the transaction is posted with, and the
+   * fee is paid from the combined balance of all spends, so a spend from a
+   * separate fee payer can neither be signed nor attributed to the fee.
+   */
+  addFeePayerSpend(note: Note, witness: object, spenderHexKey: string): void
+  /**
+   * The distinct root hashes of the given witnesses, in the order they
+   * were first seen. Witnesses for the spends of one transaction should
+   * share a single anchor, so more than one root usually means that one of
+   * the witnesses is stale.
+   */
+  validateWitnessRoots(witnesses: Array<object>): Array<Buffer>
   /** Mint a new asset with a given value as part of this transaction. */
   mint(asset: Asset, value: bigint, transferOwnershipTo?: string | undefined | null): void
   /** Burn some supply of a given asset and value as part of this transaction. */
   burn(assetIdJsBytes: Buffer, value: bigint): void
+  /**
+   * Map the hex identifier of every asset that cannot be balanced after
+   * paying the intended fee to its (negative) balance, which is the amount
+   * missing from the spends. Empty if the transaction can be posted.
+   * Surpluses are not reported since they are returned as change.
+   */
+  checkBalanced(intendedTransactionFee: bigint): Record<string, bigint>
+  /**
+   * Map the hex identifier of every burned asset to whether the amount
+   * burned exceeds the value of that asset being spent.
+   */
+  burnExceedsSpends(): Record<string, boolean>
+  /**
+   * The change note that posting with the given fee would create for the
+   * asset, or null if the asset has no change. Change goes to the spender.
+   * The note matches the posted change note in everything but its
+   * randomness.
+   */
+  buildChangeNote(spenderHexKey: string, intendedTransactionFee: bigint, assetId: Buffer): Note | null
   /**
    * Special case for posting a miners fee transaction. Miner fee transactions
    * are unique in that they generate currency. They do not have any spends
@@ -220,6 +771,12 @@ export class Transaction {
    * as the miners fee.
    */
   post_miners_fee(spenderHexKey: string): Buffer
+  /**
+   * Post a miners fee transaction paying out the given reward note, rather
+   * than one added with `output`. Used to generate reproducible fixtures.
+   * The note must be for the native asset.
+   */
+  postMinersFeeWithNote(note: Note, spenderHexKey: string): Buffer
   /**
    * Used to generate invalid miners fee transactions for testing. Call
    * post_miners_fee instead in user-facing code.
@@ -236,18 +793,116 @@ export class Transaction {
    *
    * sum(spends) - sum(outputs) - intended_transaction_fee - change = 0
    * aka: self.value_balance - intended_transaction_fee - change = 0
+   *
+   * Outputs in the posted transaction keep the order in which they were
+   * added, with any change notes appended after them.
+   *
+   * If reject_zero_value_outputs is set, posting fails when any output has
+   * a zero value.
+   */
+  post(spenderHexKey: string, changeGoesTo: string | undefined | null, intendedTransactionFee: bigint, rejectZeroValueOutputs?: boolean | undefined | null): Buffer
+  /**
+   * Post the transaction like `post`, also returning the index of the
+   * output holding the native asset change, or -1 if there is no change.
    */
-  post(spenderHexKey: string, changeGoesTo: string | undefined | null, intendedTransactionFee: bigint): Buffer
+  postTracked(spenderHexKey: string, changeGoesTo: string | undefined | null, intendedTransactionFee: bigint): NativeTrackedPost
+  /**
+   * Post the transaction like `post`, failing if the serialized posted
+   * transaction is larger than `max_bytes`. The size is only known after
+   * proving, so an oversized transaction still costs a full post.
+   */
+  postWithMaxSize(spenderHexKey: string, changeGoesTo: string | undefined | null, intendedTransactionFee: bigint, maxBytes: number): Buffer
   build(proofGenerationKeyStr: string, viewKeyStr: string, outgoingViewKeyStr: string, publicAddressStr: string, intendedTransactionFee: bigint, changeGoesTo?: string | undefined | null): Buffer
+  /**
+   * Build the transaction like `build`, returning an `UnsignedTransaction`
+   * directly rather than its serialized bytes.
+   */
+  buildUnsigned(proofGenerationKeyStr: string, viewKeyStr: string, outgoingViewKeyStr: string, publicAddressStr: string, intendedTransactionFee: bigint, changeGoesTo?: string | undefined | null): UnsignedTransaction
   setExpiration(sequence: number): void
+  /**
+   * Set the transaction to expire `ttl` blocks after `current_sequence`.
+   * Throws if the expiration sequence would overflow.
+   */
+  setExpirationTtl(currentSequence: number, ttl: number): void
+  /**
+   * Clear the pending spends and outputs, and the note material they hold,
+   * from memory. The transaction is unusable after this is called.
+   */
+  zeroize(): void
 }
 export type NativeUnsignedTransaction = UnsignedTransaction
 export class UnsignedTransaction {
   constructor(jsBytes: Buffer)
   serialize(): Buffer
+  /**
+   * The canonical bytes covered by the transaction's signatures, excluding
+   * the signatures themselves. Their Blake2b hash, personalized with
+   * `IFsighsh`, is the hash that signers sign.
+   */
+  signableBytes(): Buffer
   publicKeyRandomness(): string
+  /**
+   * A JSON description of the transaction for display on a signing
+   * device. Keys are always written in the same order, without whitespace,
+   * and amounts are decimal strings, so the same transaction always yields
+   * the same bytes. Output values are only included when an outgoing view
+   * key that can decrypt the output is given.
+   */
+  toDisplayJson(outgoingHexKey?: string | undefined | null): string
+  /**
+   * Serialize this transaction together with the commitments and signature
+   * shares collected for it so far, so that a coordinator can resume the
+   * signing session after a restart with `importSession`. The blob holds
+   * the length-prefixed transaction, the length-prefixed commitments as
+   * written by `serializeCommitments`, and then the signature shares as
+   * written by `serializeSignatureShares`.
+   */
+  exportSession(commitments: Record<string, SigningCommitments>, signatureShares: Record<string, string>): Buffer
+  /**
+   * Restore a signing session written by `exportSession`. The transaction
+   * is returned serialized, to be passed to the `UnsignedTransaction`
+   * constructor.
+   */
+  static importSession(jsBytes: Buffer): NativeSigningSession
   signingPackage(nativeCommitments: Record<string, SigningCommitments>): string
+  /**
+   * Whether the signing package asks signers to sign this transaction's
+   * signature hash. Check this before producing a signature share for a
+   * package received from a coordinator.
+   */
+  matchesSigningPackage(signingPackageStr: string): boolean
+  /**
+   * Aggregate the signature shares and sign the transaction. There must be
+   * exactly one share from each signer that committed to the signing
+   * package.
+   */
   signFrost(publicKeyPackageStr: string, signingPackageStr: string, signatureSharesMap: Record<string, string>): Buffer
+  /**
+   * Sign with the key package of a 1-of-1 FROST account, generating the
+   * commitment, signing package, and signature share internally. Only
+   * valid when the threshold is 1.
+   *
+   * **The seed must be 8 bytes of fresh randomness for every signature.**
+   * The signing nonces are derived from it, and reusing nonces across
+   * different transactions reveals the key share. The seed is mixed with
+   * the transaction's signature hash as a safeguard, but that is not a
+   * substitute for a random seed.
+   */
+  signFrostSingle(keyPackageHex: string, seed: Buffer): Buffer
+}
+/**
+ * A witness held on the Rust side, built from a packed auth path so that
+ * spends don't need to call back into JS for every node.
+ */
+export class NativeWitness {
+  /**
+   * Build a witness from its root hash, tree size, and an auth path packed
+   * as the 32 byte sibling hash of each node followed by a bitfield with
+   * one bit per node, set when the node is on the left.
+   */
+  static fromCompressed(root: Buffer, treeSize: bigint, packed: Buffer): NativeWitness
+  /** The packed auth path, in the format read by `fromCompressed`. */
+  toCompressed(): Buffer
 }
 export class FoundBlockResult {
   randomness: string
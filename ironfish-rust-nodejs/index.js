@@ -252,14 +252,21 @@ if (!nativeBinding) {
   throw new Error(`Failed to load native binding`)
 }
 
-const { FishHashContext, roundOne, roundTwo, ParticipantSecret, ParticipantIdentity, splitSecret, contribute, verifyTransform, KEY_LENGTH, NONCE_LENGTH, BoxKeyPair, randomBytes, boxMessage, unboxMessage, RollingFilter, initSignalHandler, triggerSegfault, ASSET_ID_LENGTH, ASSET_METADATA_LENGTH, ASSET_NAME_LENGTH, ASSET_LENGTH, Asset, NOTE_ENCRYPTION_KEY_LENGTH, MAC_LENGTH, ENCRYPTED_NOTE_PLAINTEXT_LENGTH, ENCRYPTED_NOTE_LENGTH, NoteEncrypted, PUBLIC_ADDRESS_LENGTH, RANDOMNESS_LENGTH, MEMO_LENGTH, AMOUNT_VALUE_LENGTH, DECRYPTED_NOTE_LENGTH, Note, PROOF_LENGTH, TRANSACTION_SIGNATURE_LENGTH, TRANSACTION_PUBLIC_KEY_RANDOMNESS_LENGTH, TRANSACTION_EXPIRATION_LENGTH, TRANSACTION_FEE_LENGTH, LATEST_TRANSACTION_VERSION, TransactionPosted, Transaction, verifyTransactions, UnsignedTransaction, LanguageCode, generateKey, spendingKeyToWords, wordsToSpendingKey, generateKeyFromPrivateKey, initializeSapling, FoundBlockResult, ThreadPoolHandler, isValidPublicAddress } = nativeBinding
+const { FishHashContext, roundOne, roundTwo, serializeCommitments, deserializeCommitments, serializeSignatureShares, deserializeSignatureShares, ParticipantSecret, ParticipantIdentity, splitSecret, groupPublicKey, thresholdAndTotal, publicKeyPackageIdentifiers, contribute, verifyTransform, KEY_LENGTH, NONCE_LENGTH, BoxKeyPair, randomBytes, boxMessage, unboxMessage, RollingFilter, initSignalHandler, triggerSegfault, ASSET_ID_LENGTH, ASSET_METADATA_LENGTH, ASSET_NAME_LENGTH, ASSET_LENGTH, Asset, NOTE_ENCRYPTION_KEY_LENGTH, MAC_LENGTH, ENCRYPTED_NOTE_PLAINTEXT_LENGTH, ENCRYPTED_NOTE_LENGTH, decryptValue, NoteEncrypted, PUBLIC_ADDRESS_LENGTH, RANDOMNESS_LENGTH, MEMO_LENGTH, AMOUNT_VALUE_LENGTH, DECRYPTED_NOTE_LENGTH, spendDescriptionFromParts, serializeSpendDescription, deserializeSpendDescription, Note, PROOF_LENGTH, TRANSACTION_SIGNATURE_LENGTH, TRANSACTION_PUBLIC_KEY_RANDOMNESS_LENGTH, TRANSACTION_EXPIRATION_LENGTH, TRANSACTION_FEE_LENGTH, LATEST_TRANSACTION_VERSION, TransactionPosted, Transaction, verifyTransactions, verifyTransactionsTimed, verifyTransactionsStreaming, mintOwners, bumpFee, totalFees, isValidTransactionEncoding, isCanonical, transactionKind, transactionId, minFeeForRate, verifySignature, verifyFrostSignature, assetEffectDiff, sweep, filterExpired, transactionsConflict, proposedEquivalent, witnessPosition, witnessesForPositions, UnsignedTransaction, NativeWitness, LanguageCode, generateKey, spendingKeyToWords, wordsToSpendingKey, generateKeyFromPrivateKey, initializeSapling, requiredTreeSize, keysConsistent, FoundBlockResult, ThreadPoolHandler, isValidPublicAddress } = nativeBinding
 
 module.exports.FishHashContext = FishHashContext
 module.exports.roundOne = roundOne
 module.exports.roundTwo = roundTwo
+module.exports.serializeCommitments = serializeCommitments
+module.exports.deserializeCommitments = deserializeCommitments
+module.exports.serializeSignatureShares = serializeSignatureShares
+module.exports.deserializeSignatureShares = deserializeSignatureShares
 module.exports.ParticipantSecret = ParticipantSecret
 module.exports.ParticipantIdentity = ParticipantIdentity
 module.exports.splitSecret = splitSecret
+module.exports.groupPublicKey = groupPublicKey
+module.exports.thresholdAndTotal = thresholdAndTotal
+module.exports.publicKeyPackageIdentifiers = publicKeyPackageIdentifiers
 module.exports.contribute = contribute
 module.exports.verifyTransform = verifyTransform
 module.exports.KEY_LENGTH = KEY_LENGTH
@@ -280,12 +287,16 @@ module.exports.NOTE_ENCRYPTION_KEY_LENGTH = NOTE_ENCRYPTION_KEY_LENGTH
 module.exports.MAC_LENGTH = MAC_LENGTH
 module.exports.ENCRYPTED_NOTE_PLAINTEXT_LENGTH = ENCRYPTED_NOTE_PLAINTEXT_LENGTH
 module.exports.ENCRYPTED_NOTE_LENGTH = ENCRYPTED_NOTE_LENGTH
+module.exports.decryptValue = decryptValue
 module.exports.NoteEncrypted = NoteEncrypted
 module.exports.PUBLIC_ADDRESS_LENGTH = PUBLIC_ADDRESS_LENGTH
 module.exports.RANDOMNESS_LENGTH = RANDOMNESS_LENGTH
 module.exports.MEMO_LENGTH = MEMO_LENGTH
 module.exports.AMOUNT_VALUE_LENGTH = AMOUNT_VALUE_LENGTH
 module.exports.DECRYPTED_NOTE_LENGTH = DECRYPTED_NOTE_LENGTH
+module.exports.spendDescriptionFromParts = spendDescriptionFromParts
+module.exports.serializeSpendDescription = serializeSpendDescription
+module.exports.deserializeSpendDescription = deserializeSpendDescription
 module.exports.Note = Note
 module.exports.PROOF_LENGTH = PROOF_LENGTH
 module.exports.TRANSACTION_SIGNATURE_LENGTH = TRANSACTION_SIGNATURE_LENGTH
@@ -296,13 +307,35 @@ module.exports.LATEST_TRANSACTION_VERSION = LATEST_TRANSACTION_VERSION
 module.exports.TransactionPosted = TransactionPosted
 module.exports.Transaction = Transaction
 module.exports.verifyTransactions = verifyTransactions
+module.exports.verifyTransactionsTimed = verifyTransactionsTimed
+module.exports.verifyTransactionsStreaming = verifyTransactionsStreaming
+module.exports.mintOwners = mintOwners
+module.exports.bumpFee = bumpFee
+module.exports.totalFees = totalFees
+module.exports.isValidTransactionEncoding = isValidTransactionEncoding
+module.exports.isCanonical = isCanonical
+module.exports.transactionKind = transactionKind
+module.exports.transactionId = transactionId
+module.exports.minFeeForRate = minFeeForRate
+module.exports.verifySignature = verifySignature
+module.exports.verifyFrostSignature = verifyFrostSignature
+module.exports.assetEffectDiff = assetEffectDiff
+module.exports.sweep = sweep
+module.exports.filterExpired = filterExpired
+module.exports.transactionsConflict = transactionsConflict
+module.exports.proposedEquivalent = proposedEquivalent
+module.exports.witnessPosition = witnessPosition
+module.exports.witnessesForPositions = witnessesForPositions
 module.exports.UnsignedTransaction = UnsignedTransaction
+module.exports.NativeWitness = NativeWitness
 module.exports.LanguageCode = LanguageCode
 module.exports.generateKey = generateKey
 module.exports.spendingKeyToWords = spendingKeyToWords
 module.exports.wordsToSpendingKey = wordsToSpendingKey
 module.exports.generateKeyFromPrivateKey = generateKeyFromPrivateKey
 module.exports.initializeSapling = initializeSapling
+module.exports.requiredTreeSize = requiredTreeSize
+module.exports.keysConsistent = keysConsistent
 module.exports.FoundBlockResult = FoundBlockResult
 module.exports.ThreadPoolHandler = ThreadPoolHandler
 module.exports.isValidPublicAddress = isValidPublicAddress
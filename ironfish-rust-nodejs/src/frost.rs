@@ -8,7 +8,12 @@ use crate::{
 };
 use ironfish::keys::ProofGenerationKeySerializable;
 use ironfish::{
-    frost::{keys::KeyPackage, round2::Randomizer, Identifier, SigningPackage},
+    frost::{
+        frost::round1::NonceCommitment,
+        keys::{KeyPackage, PublicKeyPackage},
+        round2::{Randomizer, SignatureShare},
+        Identifier, SigningPackage,
+    },
     frost_utils::split_spender_key::split_spender_key,
     frost_utils::{round_one::round_one as round_one_rust, round_two::round_two as round_two_rust},
     participant::{Identity, Secret},
@@ -18,6 +23,15 @@ use ironfish::{
 use napi::{bindgen_prelude::*, JsBuffer};
 use napi_derive::napi;
 use rand::thread_rng;
+use std::collections::{BTreeMap, HashMap};
+
+/// Size of one entry in a serialized commitments map: the signer identifier,
+/// followed by the hiding and binding nonce commitments.
+const SERIALIZED_COMMITMENT_SIZE: usize = 3 * 32;
+
+/// Size of one entry in a serialized signature shares map: the signer
+/// identifier, followed by the signature share.
+const SERIALIZED_SIGNATURE_SHARE_SIZE: usize = 2 * 32;
 
 #[napi(object, js_name = "SigningCommitments")]
 pub struct NativeSigningCommitments {
@@ -25,6 +39,12 @@ pub struct NativeSigningCommitments {
     pub binding: String,
 }
 
+#[napi(object)]
+pub struct ThresholdAndTotal {
+    pub threshold: u32,
+    pub total: u32,
+}
+
 #[napi]
 pub fn round_one(key_package: String, seed: u32) -> Result<NativeSigningCommitments> {
     let key_package =
@@ -60,6 +80,163 @@ pub fn round_two(
     Ok(bytes_to_hex(&signature_share.serialize()))
 }
 
+/// Serialize a map of signer identifiers to signing commitments into a compact
+/// binary blob: a little-endian u32 count, followed by each entry's 32 byte
+/// identifier, hiding commitment, and binding commitment. Identifiers and
+/// commitments are validated, and entries are sorted by identifier, so equal
+/// maps always serialize to the same bytes.
+#[napi]
+pub fn serialize_commitments(
+    commitments: HashMap<String, NativeSigningCommitments>,
+) -> Result<Buffer> {
+    let mut sorted: BTreeMap<[u8; 32], ([u8; 32], [u8; 32])> = BTreeMap::new();
+    for (identifier, commitment) in commitments {
+        let identifier: [u8; 32] = hex_to_bytes(&identifier).map_err(to_napi_err)?;
+        let hiding: [u8; 32] = hex_to_bytes(&commitment.hiding).map_err(to_napi_err)?;
+        let binding: [u8; 32] = hex_to_bytes(&commitment.binding).map_err(to_napi_err)?;
+
+        Identifier::deserialize(&identifier).map_err(to_napi_err)?;
+        NonceCommitment::deserialize(hiding).map_err(to_napi_err)?;
+        NonceCommitment::deserialize(binding).map_err(to_napi_err)?;
+
+        if sorted.insert(identifier, (hiding, binding)).is_some() {
+            return Err(to_napi_err("Duplicate signing commitment identifier"));
+        }
+    }
+
+    let count: u32 = sorted
+        .len()
+        .try_into()
+        .map_err(|_| to_napi_err("Value out of range"))?;
+
+    let mut vec: Vec<u8> = Vec::with_capacity(4 + sorted.len() * SERIALIZED_COMMITMENT_SIZE);
+    vec.extend_from_slice(&count.to_le_bytes());
+    for (identifier, (hiding, binding)) in sorted {
+        vec.extend_from_slice(&identifier);
+        vec.extend_from_slice(&hiding);
+        vec.extend_from_slice(&binding);
+    }
+
+    Ok(Buffer::from(vec))
+}
+
+/// Deserialize a commitments map written by `serialize_commitments`.
+#[napi]
+pub fn deserialize_commitments(
+    js_bytes: JsBuffer,
+) -> Result<HashMap<String, NativeSigningCommitments>> {
+    let bytes = js_bytes.into_value()?;
+
+    read_commitments(bytes.as_ref())
+}
+
+pub(crate) fn read_commitments(bytes: &[u8]) -> Result<HashMap<String, NativeSigningCommitments>> {
+    if bytes.len() < 4 {
+        return Err(to_napi_err("Invalid serialized commitments length"));
+    }
+    let (count_bytes, entries) = bytes.split_at(4);
+    let count = u32::from_le_bytes(count_bytes.try_into().unwrap()) as usize;
+
+    if entries.len() != count * SERIALIZED_COMMITMENT_SIZE {
+        return Err(to_napi_err("Invalid serialized commitments length"));
+    }
+
+    let mut commitments = HashMap::with_capacity(count);
+    for entry in entries.chunks(SERIALIZED_COMMITMENT_SIZE) {
+        let (identifier, nonces) = entry.split_at(32);
+        let (hiding, binding) = nonces.split_at(32);
+
+        Identifier::deserialize(identifier.try_into().unwrap()).map_err(to_napi_err)?;
+        NonceCommitment::deserialize(hiding.try_into().unwrap()).map_err(to_napi_err)?;
+        NonceCommitment::deserialize(binding.try_into().unwrap()).map_err(to_napi_err)?;
+
+        let commitment = NativeSigningCommitments {
+            hiding: bytes_to_hex(hiding),
+            binding: bytes_to_hex(binding),
+        };
+        if commitments
+            .insert(bytes_to_hex(identifier), commitment)
+            .is_some()
+        {
+            return Err(to_napi_err("Duplicate signing commitment identifier"));
+        }
+    }
+
+    Ok(commitments)
+}
+
+/// Serialize a map of signer identifiers to signature shares, both hex
+/// encoded, into a binary blob: a little-endian u32 count, followed by each
+/// entry's 32 byte identifier and 32 byte signature share. Identifiers and
+/// shares are validated, and entries are sorted by identifier.
+#[napi]
+pub fn serialize_signature_shares(shares: HashMap<String, String>) -> Result<Buffer> {
+    let mut sorted: BTreeMap<[u8; 32], [u8; 32]> = BTreeMap::new();
+    for (identifier, share) in shares {
+        let identifier: [u8; 32] = hex_to_bytes(&identifier).map_err(to_napi_err)?;
+        let share: [u8; 32] = hex_to_bytes(&share).map_err(to_napi_err)?;
+
+        Identifier::deserialize(&identifier).map_err(to_napi_err)?;
+        SignatureShare::deserialize(share).map_err(to_napi_err)?;
+
+        if sorted.insert(identifier, share).is_some() {
+            return Err(to_napi_err("Duplicate signature share identifier"));
+        }
+    }
+
+    let count: u32 = sorted
+        .len()
+        .try_into()
+        .map_err(|_| to_napi_err("Value out of range"))?;
+
+    let mut vec: Vec<u8> = Vec::with_capacity(4 + sorted.len() * SERIALIZED_SIGNATURE_SHARE_SIZE);
+    vec.extend_from_slice(&count.to_le_bytes());
+    for (identifier, share) in sorted {
+        vec.extend_from_slice(&identifier);
+        vec.extend_from_slice(&share);
+    }
+
+    Ok(Buffer::from(vec))
+}
+
+/// Deserialize a signature shares map written by
+/// `serialize_signature_shares`, validating each identifier and share.
+#[napi]
+pub fn deserialize_signature_shares(js_bytes: JsBuffer) -> Result<HashMap<String, String>> {
+    let bytes = js_bytes.into_value()?;
+
+    read_signature_shares(bytes.as_ref())
+}
+
+pub(crate) fn read_signature_shares(bytes: &[u8]) -> Result<HashMap<String, String>> {
+    if bytes.len() < 4 {
+        return Err(to_napi_err("Invalid serialized signature shares length"));
+    }
+    let (count_bytes, entries) = bytes.split_at(4);
+    let count = u32::from_le_bytes(count_bytes.try_into().unwrap()) as usize;
+
+    if entries.len() != count * SERIALIZED_SIGNATURE_SHARE_SIZE {
+        return Err(to_napi_err("Invalid serialized signature shares length"));
+    }
+
+    let mut shares = HashMap::with_capacity(count);
+    for entry in entries.chunks(SERIALIZED_SIGNATURE_SHARE_SIZE) {
+        let (identifier, share) = entry.split_at(32);
+
+        Identifier::deserialize(identifier.try_into().unwrap()).map_err(to_napi_err)?;
+        SignatureShare::deserialize(share.try_into().unwrap()).map_err(to_napi_err)?;
+
+        if shares
+            .insert(bytes_to_hex(identifier), bytes_to_hex(share))
+            .is_some()
+        {
+            return Err(to_napi_err("Duplicate signature share identifier"));
+        }
+    }
+
+    Ok(shares)
+}
+
 #[napi]
 pub struct ParticipantSecret {
     secret: Secret,
@@ -176,3 +353,69 @@ pub fn split_secret(
         public_key_package: bytes_to_hex(&public_key_package),
     })
 }
+
+/// The group verifying key of a public key package. Combined with the
+/// transaction's `public_key_randomness`, this gives the randomized key that
+/// the aggregated FROST signature is checked against.
+#[napi]
+pub fn group_public_key(public_key_package: String) -> Result<String> {
+    let public_key_package =
+        PublicKeyPackage::deserialize(&hex_to_vec_bytes(&public_key_package).map_err(to_napi_err)?)
+            .map_err(to_napi_err)?;
+
+    Ok(bytes_to_hex(
+        &public_key_package.verifying_key().serialize(),
+    ))
+}
+
+/// The number of signers required to sign, and the total number of signers,
+/// for a FROST account. A public key package does not record the signing
+/// threshold, so it is read from the key package of any of the signers.
+#[napi]
+pub fn threshold_and_total(
+    public_key_package: String,
+    key_package: String,
+) -> Result<ThresholdAndTotal> {
+    let public_key_package =
+        PublicKeyPackage::deserialize(&hex_to_vec_bytes(&public_key_package).map_err(to_napi_err)?)
+            .map_err(to_napi_err)?;
+    let key_package =
+        KeyPackage::deserialize(&hex_to_vec_bytes(&key_package).map_err(to_napi_err)?)
+            .map_err(to_napi_err)?;
+
+    if key_package.verifying_key() != public_key_package.verifying_key() {
+        return Err(to_napi_err(
+            "Key package does not belong to the public key package",
+        ));
+    }
+
+    let total = public_key_package
+        .signer_pubkeys()
+        .len()
+        .try_into()
+        .map_err(|_| to_napi_err("Value out of range"))?;
+
+    Ok(ThresholdAndTotal {
+        threshold: (*key_package.min_signers()).into(),
+        total,
+    })
+}
+
+/// The hex encoded identifiers of every participant in a public key package,
+/// sorted.
+#[napi]
+pub fn public_key_package_identifiers(public_key_package_str: String) -> Result<Vec<String>> {
+    let public_key_package = PublicKeyPackage::deserialize(
+        &hex_to_vec_bytes(&public_key_package_str).map_err(to_napi_err)?,
+    )
+    .map_err(to_napi_err)?;
+
+    let mut identifiers: Vec<String> = public_key_package
+        .signer_pubkeys()
+        .keys()
+        .map(|identifier| bytes_to_hex(&identifier.serialize()))
+        .collect();
+    identifiers.sort();
+
+    Ok(identifiers)
+}
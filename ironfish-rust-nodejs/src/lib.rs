@@ -5,11 +5,14 @@
 use std::fmt::Display;
 
 use ironfish::keys::Language;
+use ironfish::keys::ProofGenerationKey;
 use ironfish::keys::ProofGenerationKeySerializable;
 use ironfish::PublicAddress;
 use ironfish::SaplingKey;
+use ironfish::ViewKey;
 
 use napi::bindgen_prelude::*;
+use napi::JsBuffer;
 use napi_derive::napi;
 
 use ironfish::mining;
@@ -27,6 +30,15 @@ fn to_napi_err(err: impl Display) -> napi::Error {
     Error::from_reason(err.to_string())
 }
 
+/// Read a BigInt as a u64, rejecting negative values and values too large to
+/// fit rather than silently wrapping or truncating them.
+fn bigint_to_u64(value: &BigInt) -> Result<u64> {
+    match value.get_u64() {
+        (false, value, true) => Ok(value),
+        _ => Err(to_napi_err("Value out of range")),
+    }
+}
+
 // unfortunately napi doesn't support reexport of enums (bip39::Language) so we
 // have to recreate if we want type safety. hopefully in the future this will work with napi:
 // #[napi]
@@ -170,6 +182,55 @@ impl ThreadPoolHandler {
     }
 }
 
+/// The smallest note commitment tree size that includes notes at all of the
+/// given positions, i.e. the largest position plus one.
+#[napi]
+pub fn required_tree_size(positions: Vec<BigInt>) -> Result<u32> {
+    let positions = positions
+        .iter()
+        .map(bigint_to_u64)
+        .collect::<Result<Vec<u64>>>()?;
+
+    ironfish::witness::required_tree_size(&positions).map_err(to_napi_err)
+}
+
+/// Returns true if the proof generation key and view key belong to the same
+/// account. Building a transaction with mismatched keys produces an unsigned
+/// transaction that cannot be signed correctly.
+#[napi]
+pub fn keys_consistent(proof_generation_key: String, view_key: String) -> Result<bool> {
+    let proof_generation_key =
+        ProofGenerationKey::from_hex(&proof_generation_key).map_err(to_napi_err)?;
+    let view_key = ViewKey::from_hex(&view_key).map_err(to_napi_err)?;
+
+    Ok(view_key.matches_proof_generation_key(&proof_generation_key))
+}
+
+/// Verify transactions against the given serialized Groth16 verifying keys
+/// instead of the built-in ones, until `reset_verifying_keys` is called. Only
+/// available with the `verifying-keys-override` feature.
+#[cfg(feature = "verifying-keys-override")]
+#[napi]
+pub fn set_verifying_keys(
+    spend_vk: JsBuffer,
+    output_vk: JsBuffer,
+    mint_vk: JsBuffer,
+) -> Result<()> {
+    let spend_vk = spend_vk.into_value()?;
+    let output_vk = output_vk.into_value()?;
+    let mint_vk = mint_vk.into_value()?;
+
+    sapling_bls12::set_verifying_keys(spend_vk.as_ref(), output_vk.as_ref(), mint_vk.as_ref())
+        .map_err(to_napi_err)
+}
+
+/// Go back to verifying transactions against the built-in verifying keys.
+#[cfg(feature = "verifying-keys-override")]
+#[napi]
+pub fn reset_verifying_keys() {
+    sapling_bls12::reset_verifying_keys();
+}
+
 #[napi]
 pub fn is_valid_public_address(hex_address: String) -> bool {
     PublicAddress::from_hex(&hex_address).is_ok()
@@ -7,8 +7,9 @@ use ironfish::{
         asset::{
             Asset, ASSET_LENGTH as SERIALIZED_ASSET_LENGTH, ID_LENGTH, METADATA_LENGTH, NAME_LENGTH,
         },
-        asset_identifier::NATIVE_ASSET,
+        asset_identifier::{AssetIdentifier, NATIVE_ASSET},
     },
+    serializing::bytes_to_hex,
     PublicAddress,
 };
 use napi::{
@@ -19,6 +20,15 @@ use napi_derive::napi;
 
 use crate::to_napi_err;
 
+/// Decode zero-padded asset bytes into a string, dropping the padding and any
+/// surrounding whitespace.
+fn bytes_to_trimmed_string(bytes: &[u8]) -> String {
+    String::from_utf8_lossy(bytes)
+        .trim_end_matches('\0')
+        .trim()
+        .to_string()
+}
+
 #[napi]
 pub const ASSET_ID_LENGTH: u32 = ID_LENGTH as u32;
 
@@ -52,6 +62,33 @@ impl NativeAsset {
         })
     }
 
+    /// Rebuild an asset from its creator, name, and metadata, failing if the
+    /// derived identifier does not match `expected_id`.
+    #[napi(factory)]
+    pub fn from_parts(
+        creator_public_address: String,
+        name: String,
+        metadata: String,
+        expected_id: JsBuffer,
+    ) -> Result<NativeAsset> {
+        let public_address =
+            PublicAddress::from_hex(&creator_public_address).map_err(to_napi_err)?;
+
+        let expected_id_bytes = expected_id.into_value()?;
+        let expected_id = AssetIdentifier::new(
+            expected_id_bytes
+                .as_ref()
+                .try_into()
+                .map_err(|_| to_napi_err("Invalid asset identifier length"))?,
+        )
+        .map_err(to_napi_err)?;
+
+        Ok(NativeAsset {
+            asset: Asset::from_parts(public_address, &name, &metadata, &expected_id)
+                .map_err(to_napi_err)?,
+        })
+    }
+
     #[napi]
     pub fn metadata(&self) -> Buffer {
         Buffer::from(self.asset.metadata())
@@ -72,6 +109,24 @@ impl NativeAsset {
         Buffer::from(&self.asset.creator()[..])
     }
 
+    /// The asset name as a string, without the zero byte padding
+    #[napi]
+    pub fn name_string(&self) -> String {
+        bytes_to_trimmed_string(self.asset.name())
+    }
+
+    /// The asset metadata as a string, without the zero byte padding
+    #[napi]
+    pub fn metadata_string(&self) -> String {
+        bytes_to_trimmed_string(self.asset.metadata())
+    }
+
+    /// The public address of the asset creator, in hex
+    #[napi]
+    pub fn creator_hex(&self) -> String {
+        bytes_to_hex(&self.asset.creator())
+    }
+
     #[napi]
     pub fn native_id() -> Buffer {
         Buffer::from(&NATIVE_ASSET.as_bytes()[..])
@@ -82,6 +137,13 @@ impl NativeAsset {
         Buffer::from(&self.asset.id().as_bytes()[..])
     }
 
+    /// Whether the asset identifier is the one derived from the creator,
+    /// name, metadata, and nonce.
+    #[napi]
+    pub fn verify(&self) -> bool {
+        self.asset.verify().is_ok()
+    }
+
     #[napi]
     pub fn serialize(&self) -> Result<Buffer> {
         let mut vec: Vec<u8> = vec![];
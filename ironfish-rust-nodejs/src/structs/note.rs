@@ -5,7 +5,7 @@
 use ironfish::{
     assets::asset::ID_LENGTH as ASSET_ID_LENGTH,
     note::{AMOUNT_VALUE_SIZE, MEMO_SIZE, SCALAR_SIZE},
-    ViewKey,
+    SaplingKey, ViewKey,
 };
 use napi::{bindgen_prelude::*, JsBuffer};
 use napi_derive::napi;
@@ -97,6 +97,14 @@ impl NativeNote {
         Buffer::from(&self.note.commitment()[..])
     }
 
+    /// The commitment of the note, which is the same as `hash`. It depends
+    /// only on the note's contents, so it is stable across serialization and
+    /// identifies the note regardless of the transaction it appears in.
+    #[napi]
+    pub fn commitment(&self) -> Buffer {
+        Buffer::from(&self.note.commitment()[..])
+    }
+
     /// Value this note represents.
     #[napi]
     pub fn value(&self) -> u64 {
@@ -145,4 +153,30 @@ impl NativeNote {
 
         Ok(Buffer::from(nullifier))
     }
+
+    /// Create a brand-new note owned by `address`, carrying the same asset,
+    /// value, and memo as this one, sent by the owner of `spender_hex_key`.
+    ///
+    /// This is not a re-encryption of this note: the new note has fresh
+    /// randomness and its own commitment, and nothing on chain links the two.
+    /// Fails unless the spender owns this note.
+    #[napi]
+    pub fn reissue_to(&self, address: String, spender_hex_key: String) -> Result<NativeNote> {
+        let owner_address = ironfish::PublicAddress::from_hex(&address).map_err(to_napi_err)?;
+        let spender_key = SaplingKey::from_hex(&spender_hex_key).map_err(to_napi_err)?;
+
+        if spender_key.public_address() != self.note.owner() {
+            return Err(to_napi_err("Spender does not own the note"));
+        }
+
+        Ok(NativeNote {
+            note: Note::new(
+                owner_address,
+                self.note.value(),
+                self.note.memo(),
+                *self.note.asset_id(),
+                spender_key.public_address(),
+            ),
+        })
+    }
 }
@@ -2,8 +2,10 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
+use ironfish::errors::{IronfishError, IronfishErrorKind};
 use ironfish::IncomingViewKey;
 use ironfish::MerkleNoteHash;
+use ironfish::Note;
 use ironfish::OutgoingViewKey;
 use napi::bindgen_prelude::*;
 use napi::JsBuffer;
@@ -29,6 +31,47 @@ pub const ENCRYPTED_NOTE_PLAINTEXT_LENGTH: u32 = ENCRYPTED_NOTE_SIZE as u32 + MA
 pub const ENCRYPTED_NOTE_LENGTH: u32 =
     NOTE_ENCRYPTION_KEY_LENGTH + ENCRYPTED_NOTE_PLAINTEXT_LENGTH + 96;
 
+/// The outcome of decrypting a note. `status` is one of:
+///
+/// - "owned": the note decrypted with the key and `note` holds its bytes.
+/// - "notOwned": the key does not decrypt the note.
+/// - "corrupted": the key decrypts the note, but the plaintext is malformed
+///   or does not match the note commitment.
+///
+/// The encryption cannot tell a foreign key from a tampered ciphertext, since
+/// both fail authentication, so ciphertext corrupted in transit is reported as
+/// "notOwned". Only tampering that still authenticates is "corrupted".
+#[napi(object)]
+pub struct NativeDecryptionResult {
+    pub status: String,
+    pub note: Option<Buffer>,
+}
+
+impl NativeDecryptionResult {
+    fn from_decryption(decrypted: std::result::Result<Note, IronfishError>) -> Result<Self> {
+        match decrypted {
+            Ok(note) => {
+                let mut vec = vec![];
+                note.write(&mut vec).map_err(to_napi_err)?;
+                Ok(NativeDecryptionResult {
+                    status: "owned".to_string(),
+                    note: Some(Buffer::from(vec)),
+                })
+            }
+            Err(e) if e.kind == IronfishErrorKind::InvalidDecryptionKey => {
+                Ok(NativeDecryptionResult {
+                    status: "notOwned".to_string(),
+                    note: None,
+                })
+            }
+            Err(_) => Ok(NativeDecryptionResult {
+                status: "corrupted".to_string(),
+                note: None,
+            }),
+        }
+    }
+}
+
 #[napi(js_name = "NoteEncrypted")]
 pub struct NativeNoteEncrypted {
     pub(crate) note: MerkleNote,
@@ -130,4 +173,50 @@ impl NativeNoteEncrypted {
             },
         )
     }
+
+    /// Decrypt the note like `decryptNoteForOwner`, reporting whether the key
+    /// does not own the note or the note is corrupted rather than returning
+    /// undefined for both.
+    #[napi]
+    pub fn try_decrypt_note_for_owner(
+        &self,
+        incoming_hex_key: String,
+    ) -> Result<NativeDecryptionResult> {
+        let incoming_view_key =
+            IncomingViewKey::from_hex(&incoming_hex_key).map_err(to_napi_err)?;
+
+        NativeDecryptionResult::from_decryption(
+            self.note.decrypt_note_for_owner(&incoming_view_key),
+        )
+    }
+
+    /// Decrypt the note like `decryptNoteForSpender`, reporting whether the
+    /// key did not send the note or the note is corrupted rather than
+    /// returning undefined for both.
+    #[napi]
+    pub fn try_decrypt_note_for_spender(
+        &self,
+        outgoing_hex_key: String,
+    ) -> Result<NativeDecryptionResult> {
+        let outgoing_view_key =
+            OutgoingViewKey::from_hex(&outgoing_hex_key).map_err(to_napi_err)?;
+
+        NativeDecryptionResult::from_decryption(
+            self.note.decrypt_note_for_spender(&outgoing_view_key),
+        )
+    }
+}
+
+/// Decrypt only the value of an encrypted note with the owner's incoming view
+/// key. The value is not checked against the note commitment, so this is meant
+/// for balance scans rather than for notes about to be spent.
+///
+/// Returns undefined if the note was unable to be decrypted with the given key.
+#[napi]
+pub fn decrypt_value(encrypted_note: JsBuffer, incoming_hex_key: String) -> Result<Option<u64>> {
+    let bytes = encrypted_note.into_value()?;
+    let note = MerkleNote::read(bytes.as_ref()).map_err(to_napi_err)?;
+    let incoming_view_key = IncomingViewKey::from_hex(&incoming_hex_key).map_err(to_napi_err)?;
+
+    Ok(note.decrypt_value_for_owner(&incoming_view_key).ok())
 }
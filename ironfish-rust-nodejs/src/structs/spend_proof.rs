@@ -5,9 +5,66 @@
 use napi::bindgen_prelude::*;
 use napi_derive::napi;
 
+use crate::to_napi_err;
+
+/// Length of a spend description serialized with `serializeSpendDescription`:
+/// the tree size as a little endian u32, then the root hash and nullifier.
+const SERIALIZED_SPEND_DESCRIPTION_LENGTH: usize = 4 + 32 + 32;
+
 #[napi(object)]
 pub struct NativeSpendDescription {
     pub tree_size: u32,
     pub root_hash: Buffer,
     pub nullifier: Buffer,
 }
+
+/// Build a spend description from its parts, such as when loading one from
+/// storage to compare with `TransactionPosted.getSpend`. The root hash and
+/// nullifier must be 32 bytes each.
+#[napi]
+pub fn spend_description_from_parts(
+    tree_size: u32,
+    root_hash: Buffer,
+    nullifier: Buffer,
+) -> Result<NativeSpendDescription> {
+    if root_hash.len() != 32 {
+        return Err(to_napi_err("Invalid root hash length"));
+    }
+    if nullifier.len() != 32 {
+        return Err(to_napi_err("Invalid nullifier length"));
+    }
+
+    Ok(NativeSpendDescription {
+        tree_size,
+        root_hash,
+        nullifier,
+    })
+}
+
+#[napi]
+pub fn serialize_spend_description(spend: NativeSpendDescription) -> Result<Buffer> {
+    let spend = spend_description_from_parts(spend.tree_size, spend.root_hash, spend.nullifier)?;
+
+    let mut bytes = Vec::with_capacity(SERIALIZED_SPEND_DESCRIPTION_LENGTH);
+    bytes.extend_from_slice(&spend.tree_size.to_le_bytes());
+    bytes.extend_from_slice(spend.root_hash.as_ref());
+    bytes.extend_from_slice(spend.nullifier.as_ref());
+
+    Ok(Buffer::from(bytes))
+}
+
+#[napi]
+pub fn deserialize_spend_description(bytes: Buffer) -> Result<NativeSpendDescription> {
+    if bytes.len() != SERIALIZED_SPEND_DESCRIPTION_LENGTH {
+        return Err(to_napi_err("Invalid spend description length"));
+    }
+
+    let mut tree_size = [0; 4];
+    tree_size.copy_from_slice(&bytes[..4]);
+
+    Ok(NativeSpendDescription {
+        tree_size: u32::from_le_bytes(tree_size),
+        root_hash: Buffer::from(&bytes[4..36]),
+        nullifier: Buffer::from(&bytes[36..]),
+    })
+}
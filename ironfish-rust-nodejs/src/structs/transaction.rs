@@ -4,39 +4,51 @@
 
 use std::cell::RefCell;
 
-use std::collections::{BTreeMap, HashMap};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::convert::TryInto;
+use std::time::Instant;
 
-use ironfish::assets::asset_identifier::AssetIdentifier;
+use ironfish::assets::asset_identifier::{AssetIdentifier, NATIVE_ASSET};
 use ironfish::frost::frost::round1::NonceCommitment;
-use ironfish::frost::keys::PublicKeyPackage;
+use ironfish::frost::keys::{KeyPackage, PublicKeyPackage};
 use ironfish::frost::round1::SigningCommitments;
-use ironfish::frost::round2::SignatureShare;
+use ironfish::frost::round2::{Randomizer, SignatureShare};
 use ironfish::frost::Identifier;
 use ironfish::frost::SigningPackage;
 use ironfish::serializing::hex_to_vec_bytes;
 use ironfish::serializing::{bytes_to_hex, hex_to_bytes};
 use ironfish::transaction::unsigned::UnsignedTransaction;
 use ironfish::transaction::{
-    batch_verify_transactions, TransactionVersion, TRANSACTION_EXPIRATION_SIZE,
+    batch_verify_transaction_proofs, batch_verify_transactions, verify_transaction,
+    verify_transaction_signatures, TransactionVersion, TRANSACTION_EXPIRATION_SIZE,
     TRANSACTION_FEE_SIZE, TRANSACTION_PUBLIC_KEY_SIZE, TRANSACTION_SIGNATURE_SIZE,
 };
+use ironfish::witness::WitnessTrait;
 use ironfish::{
     keys::proof_generation_key::{ProofGenerationKey, ProofGenerationKeySerializable},
-    MerkleNoteHash, OutgoingViewKey, ProposedTransaction, PublicAddress, SaplingKey, Transaction,
-    ViewKey,
+    redjubjub, IncomingViewKey, MerkleNoteHash, Note, OutgoingViewKey, ProposedTransaction,
+    PublicAddress, SaplingKey, Transaction, ViewKey,
 };
 use napi::{
     bindgen_prelude::{i64n, BigInt, Buffer, Env, Object, Result, Undefined},
     JsBuffer,
 };
 use napi_derive::napi;
-
-use crate::{frost::NativeSigningCommitments, to_napi_err};
+#[cfg(feature = "deterministic-rng")]
+use rand::{rngs::StdRng, SeedableRng};
+
+use crate::{
+    bigint_to_u64,
+    frost::{
+        read_commitments, read_signature_shares, serialize_commitments, serialize_signature_shares,
+        NativeSigningCommitments,
+    },
+    to_napi_err,
+};
 
 use super::note::NativeNote;
 use super::spend_proof::NativeSpendDescription;
-use super::witness::JsWitness;
+use super::witness::{JsWitness, NativeWitness};
 use super::{NativeAsset, ENCRYPTED_NOTE_LENGTH};
 use ironfish::transaction::outputs::PROOF_SIZE;
 
@@ -58,9 +70,71 @@ pub const TRANSACTION_FEE_LENGTH: u32 = TRANSACTION_FEE_SIZE as u32;
 #[napi]
 pub const LATEST_TRANSACTION_VERSION: u8 = TransactionVersion::latest() as u8;
 
+#[napi(object)]
+pub struct NativeTransactionRecipient {
+    pub address: String,
+    pub value: BigInt,
+    pub asset_id: Buffer,
+}
+
+#[napi(object)]
+pub struct NativeOutputPosition {
+    pub commitment: Buffer,
+    pub position: BigInt,
+}
+
+#[napi(object)]
+pub struct NativeTrackedPost {
+    pub transaction: Buffer,
+    pub change_index: i32,
+}
+
+#[napi(object)]
+pub struct NativeTreeSizeRange {
+    pub min: u32,
+    pub max: u32,
+}
+
+#[napi(object)]
+pub struct NativeTransactionSummary {
+    pub version: u8,
+    pub fee: BigInt,
+    pub expiration: u32,
+    pub spends_count: u32,
+    pub outputs_count: u32,
+    pub mints_count: u32,
+    pub burns_count: u32,
+    pub nullifiers: Vec<Buffer>,
+    pub output_commitments: Vec<Buffer>,
+    pub asset_ids: Vec<Buffer>,
+}
+
+#[napi(object)]
+pub struct NativeVerificationTimings {
+    pub ok: bool,
+    pub parse_ms: f64,
+    pub proof_ms: f64,
+    pub binding_ms: f64,
+}
+
+#[napi(object)]
+pub struct NativeSigningSession {
+    pub transaction: Buffer,
+    pub commitments: HashMap<String, NativeSigningCommitments>,
+    pub signature_shares: HashMap<String, String>,
+}
+
+#[napi(object)]
+pub struct NativeAssetEffect {
+    pub minted: BigInt,
+    pub burned: BigInt,
+    pub net: BigInt,
+}
+
 #[napi(js_name = "TransactionPosted")]
 pub struct NativeTransactionPosted {
     transaction: Transaction,
+    binding_signature: [u8; TRANSACTION_SIGNATURE_SIZE],
 }
 
 #[napi]
@@ -71,7 +145,16 @@ impl NativeTransactionPosted {
 
         let transaction = Transaction::read(bytes.as_ref()).map_err(to_napi_err)?;
 
-        Ok(NativeTransactionPosted { transaction })
+        let mut binding_signature = [0; TRANSACTION_SIGNATURE_SIZE];
+        transaction
+            .binding_signature()
+            .write(&mut binding_signature[..])
+            .map_err(to_napi_err)?;
+
+        Ok(NativeTransactionPosted {
+            transaction,
+            binding_signature,
+        })
     }
 
     #[napi]
@@ -82,6 +165,82 @@ impl NativeTransactionPosted {
         Ok(Buffer::from(vec))
     }
 
+    /// The transaction's public fields in a single object: version, fee,
+    /// expiration, description counts, the nullifiers of the spends, the note
+    /// commitments of the outputs, and the distinct identifiers of the assets
+    /// minted or burned, in order of first appearance.
+    #[napi]
+    pub fn summary(&self) -> Result<NativeTransactionSummary> {
+        let count = |len: usize| -> Result<u32> {
+            len.try_into()
+                .map_err(|_| to_napi_err("Value out of range"))
+        };
+
+        let nullifiers = self
+            .transaction
+            .spends()
+            .iter()
+            .map(|spend| Buffer::from(&spend.nullifier().0[..]))
+            .collect();
+
+        let output_commitments = self
+            .transaction
+            .outputs()
+            .iter()
+            .map(|output| Buffer::from(&output.merkle_note().merkle_hash().0.to_bytes_le()[..]))
+            .collect();
+
+        let mut asset_ids: Vec<AssetIdentifier> = vec![];
+        let minted = self.transaction.mints().iter().map(|mint| *mint.asset.id());
+        let burned = self.transaction.burns().iter().map(|burn| burn.asset_id);
+        for asset_id in minted.chain(burned) {
+            if !asset_ids.contains(&asset_id) {
+                asset_ids.push(asset_id);
+            }
+        }
+
+        Ok(NativeTransactionSummary {
+            version: self.transaction.version().as_u8(),
+            fee: BigInt::from(self.transaction.fee()),
+            expiration: self.transaction.expiration(),
+            spends_count: count(self.transaction.spends().len())?,
+            outputs_count: count(self.transaction.outputs().len())?,
+            mints_count: count(self.transaction.mints().len())?,
+            burns_count: count(self.transaction.burns().len())?,
+            nullifiers,
+            output_commitments,
+            asset_ids: asset_ids
+                .iter()
+                .map(|asset_id| Buffer::from(&asset_id.as_bytes()[..]))
+                .collect(),
+        })
+    }
+
+    /// A compact record of the transaction's public metadata for archival
+    /// indexes, without proofs, encrypted notes, or signatures. It cannot be
+    /// deserialized into a transaction or verified.
+    ///
+    /// All integers are little-endian. The record holds the version byte, the
+    /// fee (i64), and the expiration (u32), followed by spend, output, mint,
+    /// and burn sections, each starting with a u32 count of its entries:
+    /// spends are a nullifier, tree size (u32), and root hash; outputs are a
+    /// note commitment; mints and burns are an asset identifier and value
+    /// (u64). Hashes and identifiers are 32 bytes.
+    #[napi]
+    pub fn serialize_metadata_only(&self) -> Result<Buffer> {
+        let mut vec: Vec<u8> = vec![];
+        self.transaction
+            .write_metadata(&mut vec)
+            .map_err(to_napi_err)?;
+
+        Ok(Buffer::from(vec))
+    }
+
+    #[napi]
+    pub fn version(&self) -> u8 {
+        self.transaction.version().as_u8()
+    }
+
     #[napi]
     pub fn notes_length(&self) -> Result<i64> {
         let notes_len: i64 = self
@@ -107,6 +266,329 @@ impl NativeTransactionPosted {
         Ok(Buffer::from(vec))
     }
 
+    /// The serialized merkle notes of every output, in output order. Same as
+    /// calling `getNote` for each index.
+    #[napi]
+    pub fn all_encrypted_notes(&self) -> Result<Vec<Buffer>> {
+        self.transaction
+            .outputs()
+            .iter()
+            .map(|output| {
+                let mut vec: Vec<u8> = Vec::with_capacity(ENCRYPTED_NOTE_LENGTH as usize);
+                output.merkle_note().write(&mut vec).map_err(to_napi_err)?;
+                Ok(Buffer::from(vec))
+            })
+            .collect()
+    }
+
+    /// Whether the note's commitment matches the commitment of one of the
+    /// outputs, i.e. the note was created by this transaction.
+    #[napi]
+    pub fn contains_output(&self, note: &NativeNote) -> bool {
+        let commitment = note.note.commitment();
+
+        self.transaction
+            .outputs()
+            .iter()
+            .any(|output| output.merkle_note().merkle_hash().0.to_bytes_le() == commitment)
+    }
+
+    /// The outgoing cipher text of the output at the given index, which the
+    /// sender can decrypt with their outgoing view key to recover the note.
+    #[napi]
+    pub fn output_outgoing_ciphertext(&self, index: i64) -> Result<Buffer> {
+        let index_usize: usize = index
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        let output = self
+            .transaction
+            .outputs()
+            .get(index_usize)
+            .ok_or_else(|| to_napi_err("Value out of range"))?;
+
+        Ok(Buffer::from(
+            &output.merkle_note().note_encryption_keys()[..],
+        ))
+    }
+
+    /// Whether the output at the given index decrypts with the given incoming
+    /// view key to a note of the expected value. False if the output cannot
+    /// be decrypted with the key.
+    #[napi]
+    pub fn verify_output_claim(
+        &self,
+        index: i64,
+        expected_value: BigInt,
+        incoming_view_key_hex: String,
+    ) -> Result<bool> {
+        let index_usize: usize = index
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        let output = self
+            .transaction
+            .outputs()
+            .get(index_usize)
+            .ok_or_else(|| to_napi_err("Value out of range"))?;
+
+        let incoming_view_key =
+            IncomingViewKey::from_hex(&incoming_view_key_hex).map_err(to_napi_err)?;
+
+        let (signed, expected_value, lossless) = expected_value.get_u64();
+        if signed || !lossless {
+            return Ok(false);
+        }
+
+        Ok(
+            match output
+                .merkle_note()
+                .decrypt_note_for_owner(&incoming_view_key)
+            {
+                Ok(note) => note.value() == expected_value,
+                Err(_) => false,
+            },
+        )
+    }
+
+    /// The identifiers of the assets of every output that can be decrypted
+    /// with the given incoming view key, each listed once in output order.
+    #[napi]
+    pub fn visible_assets(&self, incoming_view_key_hex: String) -> Result<Vec<Buffer>> {
+        let incoming_view_key =
+            IncomingViewKey::from_hex(&incoming_view_key_hex).map_err(to_napi_err)?;
+
+        let mut asset_ids: Vec<AssetIdentifier> = vec![];
+        for output in self.transaction.outputs() {
+            if let Ok(note) = output
+                .merkle_note()
+                .decrypt_note_for_owner(&incoming_view_key)
+            {
+                if !asset_ids.contains(note.asset_id()) {
+                    asset_ids.push(*note.asset_id());
+                }
+            }
+        }
+
+        Ok(asset_ids
+            .iter()
+            .map(|asset_id| Buffer::from(&asset_id.as_bytes()[..]))
+            .collect())
+    }
+
+    /// The note commitment of each output and the note tree position it takes
+    /// when the transaction's outputs are appended to the tree starting at
+    /// `start_position`. Outputs occupy consecutive positions, in order.
+    #[napi]
+    pub fn assign_output_positions(
+        &self,
+        start_position: BigInt,
+    ) -> Result<Vec<NativeOutputPosition>> {
+        let start_position = bigint_to_u64(&start_position)?;
+
+        let mut positions = Vec::with_capacity(self.transaction.outputs().len());
+        for (index, output) in self.transaction.outputs().iter().enumerate() {
+            let mut commitment: Vec<u8> = Vec::with_capacity(32);
+            output
+                .merkle_note()
+                .merkle_hash()
+                .write(&mut commitment)
+                .map_err(to_napi_err)?;
+
+            let position = start_position
+                .checked_add(index as u64)
+                .ok_or_else(|| to_napi_err("Value out of range"))?;
+
+            positions.push(NativeOutputPosition {
+                commitment: Buffer::from(commitment),
+                position: BigInt::from(position),
+            });
+        }
+
+        Ok(positions)
+    }
+
+    /// The recipients of every output that can be decrypted with the given
+    /// outgoing view key, i.e. the outputs sent by the owner of that key.
+    #[napi]
+    pub fn recipients(&self, outgoing_hex_key: String) -> Result<Vec<NativeTransactionRecipient>> {
+        let outgoing_view_key =
+            OutgoingViewKey::from_hex(&outgoing_hex_key).map_err(to_napi_err)?;
+
+        let mut recipients = vec![];
+        for output in self.transaction.outputs() {
+            if let Ok(note) = output
+                .merkle_note()
+                .decrypt_note_for_spender(&outgoing_view_key)
+            {
+                recipients.push(NativeTransactionRecipient {
+                    address: note.owner().hex_public_address(),
+                    value: BigInt::from(note.value()),
+                    asset_id: Buffer::from(&note.asset_id().as_bytes()[..]),
+                });
+            }
+        }
+
+        Ok(recipients)
+    }
+
+    /// The number of unique recipient addresses among the outputs that can be
+    /// decrypted with the given outgoing view key.
+    #[napi]
+    pub fn distinct_recipients(&self, outgoing_hex_key: String) -> Result<u32> {
+        let outgoing_view_key =
+            OutgoingViewKey::from_hex(&outgoing_hex_key).map_err(to_napi_err)?;
+
+        let recipients: HashSet<[u8; 32]> = self
+            .transaction
+            .outputs()
+            .iter()
+            .filter_map(|output| {
+                output
+                    .merkle_note()
+                    .decrypt_note_for_spender(&outgoing_view_key)
+                    .ok()
+            })
+            .map(|note| note.owner().public_address())
+            .collect();
+
+        recipients
+            .len()
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))
+    }
+
+    /// Map the hex identifier of every asset sent by this transaction to the
+    /// total value sent, excluding outputs to the change address. Only
+    /// outputs that can be decrypted with the given outgoing view key are
+    /// counted.
+    #[napi]
+    pub fn output_totals(
+        &self,
+        outgoing_hex_key: String,
+        change_address: String,
+    ) -> Result<HashMap<String, BigInt>> {
+        let outgoing_view_key =
+            OutgoingViewKey::from_hex(&outgoing_hex_key).map_err(to_napi_err)?;
+        let change_address = PublicAddress::from_hex(&change_address).map_err(to_napi_err)?;
+
+        let mut totals: HashMap<AssetIdentifier, u128> = HashMap::new();
+        for output in self.transaction.outputs() {
+            if let Ok(note) = output
+                .merkle_note()
+                .decrypt_note_for_spender(&outgoing_view_key)
+            {
+                if note.owner() != change_address {
+                    *totals.entry(*note.asset_id()).or_insert(0) += note.value() as u128;
+                }
+            }
+        }
+
+        Ok(totals
+            .into_iter()
+            .map(|(asset_id, total)| (bytes_to_hex(asset_id.as_bytes()), BigInt::from(total)))
+            .collect())
+    }
+
+    /// The total value of the outputs of the given asset that can be decrypted
+    /// with the given outgoing view key, including any change.
+    #[napi]
+    pub fn output_value_for_asset(
+        &self,
+        asset_id: JsBuffer,
+        outgoing_hex_key: String,
+    ) -> Result<BigInt> {
+        let asset_id_bytes = asset_id.into_value()?;
+        let asset_id = AssetIdentifier::new(
+            asset_id_bytes
+                .as_ref()
+                .try_into()
+                .map_err(|_| to_napi_err("Invalid asset identifier length"))?,
+        )
+        .map_err(to_napi_err)?;
+        let outgoing_view_key =
+            OutgoingViewKey::from_hex(&outgoing_hex_key).map_err(to_napi_err)?;
+
+        let mut total: u128 = 0;
+        for output in self.transaction.outputs() {
+            if let Ok(note) = output
+                .merkle_note()
+                .decrypt_note_for_spender(&outgoing_view_key)
+            {
+                if note.asset_id() == &asset_id {
+                    total += note.value() as u128;
+                }
+            }
+        }
+
+        Ok(BigInt::from(total))
+    }
+
+    /// Whether two outputs that can be decrypted with the given outgoing view
+    /// key are sent to the same address. Change counts as an output to the
+    /// change address, so a payment to the change address also counts.
+    #[napi]
+    pub fn has_address_reuse(&self, outgoing_hex_key: String) -> Result<bool> {
+        let outgoing_view_key =
+            OutgoingViewKey::from_hex(&outgoing_hex_key).map_err(to_napi_err)?;
+
+        let mut recipients: HashSet<[u8; 32]> = HashSet::new();
+        for output in self.transaction.outputs() {
+            if let Ok(note) = output
+                .merkle_note()
+                .decrypt_note_for_spender(&outgoing_view_key)
+            {
+                if !recipients.insert(note.owner().public_address()) {
+                    return Ok(true);
+                }
+            }
+        }
+
+        Ok(false)
+    }
+
+    /// Whether any output that can be decrypted with the given outgoing view
+    /// key has a value below `threshold`. Output values are encrypted, so
+    /// outputs the key cannot decrypt are not counted, even if they are dust.
+    #[napi]
+    pub fn has_dust_outputs(&self, threshold: BigInt, outgoing_hex_key: String) -> Result<bool> {
+        let outgoing_view_key =
+            OutgoingViewKey::from_hex(&outgoing_hex_key).map_err(to_napi_err)?;
+        let threshold = bigint_to_u64(&threshold)?;
+
+        Ok(self.transaction.outputs().iter().any(|output| {
+            output
+                .merkle_note()
+                .decrypt_note_for_spender(&outgoing_view_key)
+                .map_or(false, |note| note.value() < threshold)
+        }))
+    }
+
+    /// Whether this transaction moves funds within a single account, such as
+    /// when consolidating notes: it spends at least one note, and every
+    /// output is sent by and to the account of the given view key. Any output
+    /// the account can't decrypt goes elsewhere.
+    #[napi]
+    pub fn is_self_transfer(&self, view_key_hex: String) -> Result<bool> {
+        let view_key = ViewKey::from_hex(&view_key_hex).map_err(to_napi_err)?;
+        let incoming_view_key = view_key.incoming_view_key().map_err(to_napi_err)?;
+        let public_address = incoming_view_key.public_address();
+
+        if self.transaction.spends().is_empty() {
+            return Ok(false);
+        }
+
+        Ok(self.transaction.outputs().iter().all(|output| {
+            match output
+                .merkle_note()
+                .decrypt_note_for_owner(&incoming_view_key)
+            {
+                Ok(note) => note.owner() == public_address && note.sender() == public_address,
+                Err(_) => false,
+            }
+        }))
+    }
+
     #[napi]
     pub fn spends_length(&self) -> Result<i64> {
         let spends_len: i64 = self
@@ -119,6 +601,20 @@ impl NativeTransactionPosted {
         Ok(spends_len)
     }
 
+    /// The total number of spend, output, mint, and burn descriptions in the
+    /// transaction.
+    #[napi]
+    pub fn description_count(&self) -> Result<i64> {
+        let count = self.transaction.spends().len()
+            + self.transaction.outputs().len()
+            + self.transaction.mints().len()
+            + self.transaction.burns().len();
+
+        count
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))
+    }
+
     #[napi]
     pub fn get_spend(&self, index: i64) -> Result<NativeSpendDescription> {
         let index_usize: usize = index
@@ -142,11 +638,150 @@ impl NativeTransactionPosted {
         })
     }
 
+    /// The smallest and largest note commitment tree sizes among the spends'
+    /// anchors, or null if the transaction has no spends.
+    #[napi]
+    pub fn spend_tree_size_range(&self) -> Option<NativeTreeSizeRange> {
+        let tree_sizes = self
+            .transaction
+            .spends()
+            .iter()
+            .map(|spend| spend.tree_size());
+
+        Some(NativeTreeSizeRange {
+            min: tree_sizes.clone().min()?,
+            max: tree_sizes.max()?,
+        })
+    }
+
+    /// The key that verifies the authorization signature of the spend at the
+    /// given index. Every spend in a transaction is signed under the
+    /// transaction's randomized public key, so this is the same for all spends.
+    #[napi]
+    pub fn spend_verifying_key(&self, index: i64) -> Result<Buffer> {
+        let index_usize: usize = index
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        if index_usize >= self.transaction.spends().len() {
+            return Err(to_napi_err("Value out of range"));
+        }
+
+        let mut vec: Vec<u8> = vec![];
+        self.transaction
+            .randomized_public_key()
+            .write(&mut vec)
+            .map_err(to_napi_err)?;
+
+        Ok(Buffer::from(vec))
+    }
+
     #[napi]
     pub fn fee(&self) -> i64n {
         i64n(self.transaction.fee())
     }
 
+    /// The fee paid per byte of the serialized transaction, which is what
+    /// block assemblers sort the mempool by.
+    #[napi]
+    pub fn fee_rate(&self) -> Result<f64> {
+        let mut vec: Vec<u8> = vec![];
+        self.transaction.write(&mut vec).map_err(to_napi_err)?;
+
+        Ok(self.transaction.fee() as f64 / vec.len() as f64)
+    }
+
+    /// Whether the transaction mints the asset with the given identifier.
+    #[napi]
+    pub fn has_mint_for(&self, asset_id: JsBuffer) -> Result<bool> {
+        let asset_id_bytes = asset_id.into_value()?;
+
+        Ok(self
+            .transaction
+            .mints()
+            .iter()
+            .any(|mint| &mint.asset.id().as_bytes()[..] == asset_id_bytes.as_ref()))
+    }
+
+    /// Whether the transaction burns the asset with the given identifier.
+    #[napi]
+    pub fn has_burn_for(&self, asset_id: JsBuffer) -> Result<bool> {
+        let asset_id_bytes = asset_id.into_value()?;
+
+        Ok(self
+            .transaction
+            .burns()
+            .iter()
+            .any(|burn| &burn.asset_id.as_bytes()[..] == asset_id_bytes.as_ref()))
+    }
+
+    /// The serialized asset committed in the mint at the given index, exactly
+    /// as it appears in the transaction.
+    #[napi]
+    pub fn mint_asset_bytes(&self, index: i64) -> Result<Buffer> {
+        let index_usize: usize = index
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        let mint = self
+            .transaction
+            .mints()
+            .get(index_usize)
+            .ok_or_else(|| to_napi_err("Value out of range"))?;
+
+        let mut vec: Vec<u8> = vec![];
+        mint.asset.write(&mut vec).map_err(to_napi_err)?;
+
+        Ok(Buffer::from(vec))
+    }
+
+    /// The public inputs the Groth16 verifier checks the proof of the spend at
+    /// the given index against, in circuit order, each as a 32 byte little
+    /// endian field element.
+    #[napi]
+    pub fn spend_public_inputs(&self, index: i64) -> Result<Vec<Buffer>> {
+        let index_usize: usize = index
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        let spend = self
+            .transaction
+            .spends()
+            .get(index_usize)
+            .ok_or_else(|| to_napi_err("Value out of range"))?;
+
+        Ok(spend
+            .public_inputs(self.transaction.randomized_public_key())
+            .iter()
+            .map(|input| Buffer::from(&input.to_bytes_le()[..]))
+            .collect())
+    }
+
+    /// The public inputs the Groth16 verifier checks the proof of the output
+    /// at the given index against, in circuit order, each as a 32 byte little
+    /// endian field element.
+    #[napi]
+    pub fn output_public_inputs(&self, index: i64) -> Result<Vec<Buffer>> {
+        let index_usize: usize = index
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        let output = self
+            .transaction
+            .outputs()
+            .get(index_usize)
+            .ok_or_else(|| to_napi_err("Value out of range"))?;
+
+        Ok(output
+            .public_inputs(self.transaction.randomized_public_key())
+            .iter()
+            .map(|input| Buffer::from(&input.to_bytes_le()[..]))
+            .collect())
+    }
+
+    /// Serializes the binding signature on every call. Prefer
+    /// `bindingSignatureBytes`, which returns the same bytes without
+    /// reserializing.
     #[napi]
     pub fn transaction_signature(&self) -> Result<Buffer> {
         let mut serialized_signature = vec![];
@@ -158,6 +793,13 @@ impl NativeTransactionPosted {
         Ok(Buffer::from(serialized_signature))
     }
 
+    /// The binding signature bytes, serialized once when the transaction was
+    /// read.
+    #[napi]
+    pub fn binding_signature_bytes(&self) -> Buffer {
+        Buffer::from(&self.binding_signature[..])
+    }
+
     #[napi]
     pub fn hash(&self) -> Result<Buffer> {
         let hash = self
@@ -168,15 +810,83 @@ impl NativeTransactionPosted {
         Ok(Buffer::from(hash.as_ref()))
     }
 
+    /// A short identifier for logging: the first 8 bytes of the transaction
+    /// hash, in hex.
+    #[napi]
+    pub fn fingerprint(&self) -> Result<String> {
+        let hash = self
+            .transaction
+            .transaction_signature_hash()
+            .map_err(to_napi_err)?;
+
+        Ok(bytes_to_hex(&hash[..8]))
+    }
+
     #[napi]
     pub fn expiration(&self) -> u32 {
         self.transaction.expiration()
     }
+
+    /// Approximate number of bytes this transaction occupies in memory,
+    /// including its proofs and descriptions.
+    #[napi]
+    pub fn memory_footprint(&self) -> Result<i64> {
+        let footprint: i64 = self
+            .transaction
+            .memory_footprint()
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        Ok(footprint)
+    }
+
+    /// Every proof in the transaction and its public inputs, laid out in a
+    /// single buffer. The buffer starts with six little-endian u32s: the
+    /// number of spends, outputs, and mints, then the byte offsets of the
+    /// spend, output, and mint sections. Each section holds its proofs
+    /// followed by their public inputs as 32 byte little-endian scalars (7 per
+    /// spend, 7 per output, 4 per mint).
+    #[napi]
+    pub fn export_proof_bundle(&self) -> Result<Buffer> {
+        let bundle = self.transaction.proof_bundle().map_err(to_napi_err)?;
+
+        Ok(Buffer::from(bundle))
+    }
+
+    /// A heuristic score from 0 to 100 of how little the transaction reveals
+    /// from its public shape. Outputs that conceal change and decoy outputs
+    /// raise the score; many spends, mints, and burns lower it.
+    #[napi]
+    pub fn privacy_score(&self) -> u8 {
+        self.transaction.privacy_score()
+    }
+
+    /// A hash over the sorted nullifiers of the transaction's spends, which
+    /// identifies the set of notes the transaction spends.
+    #[napi]
+    pub fn nullifier_set_hash(&self) -> Buffer {
+        Buffer::from(&self.transaction.nullifier_set_hash()[..])
+    }
+
+    /// The serialized transaction fields that are hashed to produce the
+    /// transaction signature hash returned by `hash`.
+    #[napi]
+    pub fn signature_hash_preimage(&self) -> Result<Buffer> {
+        let preimage = self
+            .transaction
+            .signature_hash_preimage()
+            .map_err(to_napi_err)?;
+
+        Ok(Buffer::from(preimage))
+    }
 }
 
 #[napi(js_name = "Transaction")]
 pub struct NativeTransaction {
     transaction: ProposedTransaction,
+    allowed_assets: Option<HashSet<AssetIdentifier>>,
+    #[cfg(feature = "deterministic-rng")]
+    rng: Option<StdRng>,
 }
 
 #[napi]
@@ -185,19 +895,130 @@ impl NativeTransaction {
     pub fn new(version: u8) -> Result<Self> {
         let tx_version = version.try_into().map_err(to_napi_err)?;
         let transaction = ProposedTransaction::new(tx_version);
-        Ok(NativeTransaction { transaction })
+        Ok(NativeTransaction {
+            transaction,
+            allowed_assets: None,
+            #[cfg(feature = "deterministic-rng")]
+            rng: None,
+        })
+    }
+
+    /// Restrict the assets this transaction can output, mint, or burn to the
+    /// given asset identifiers. Applies to calls made after it is set.
+    #[napi]
+    pub fn set_allowed_assets(&mut self, asset_ids: Vec<JsBuffer>) -> Result<()> {
+        let mut allowed_assets = HashSet::new();
+
+        for asset_id in asset_ids {
+            let asset_id_bytes = asset_id.into_value()?;
+            let asset_id = AssetIdentifier::new(
+                asset_id_bytes
+                    .as_ref()
+                    .try_into()
+                    .map_err(|_| to_napi_err("Invalid asset identifier length"))?,
+            )
+            .map_err(to_napi_err)?;
+            allowed_assets.insert(asset_id);
+        }
+
+        self.allowed_assets = Some(allowed_assets);
+
+        Ok(())
+    }
+
+    fn check_asset_allowed(&self, asset_id: &AssetIdentifier) -> Result<()> {
+        match &self.allowed_assets {
+            Some(allowed_assets) if !allowed_assets.contains(asset_id) => Err(to_napi_err(
+                format!("Asset {} is not allowed", bytes_to_hex(asset_id.as_bytes())),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Choose the note commitment tree size of the anchor this transaction is
+    /// built against. Spends must use a witness whose tree size is no larger
+    /// than the anchor's, otherwise they fail with an anchor mismatch.
+    #[napi]
+    pub fn set_anchor_tree_size(&mut self, tree_size: u32) -> Result<()> {
+        self.transaction
+            .set_anchor_tree_size(tree_size)
+            .map_err(to_napi_err)
+    }
+
+    /// Choose the note commitment tree root this transaction is built against.
+    /// Spends must use a witness to this root, otherwise they fail with an
+    /// anchor mismatch.
+    #[napi]
+    pub fn set_anchor(&mut self, root: JsBuffer) -> Result<()> {
+        let root_bytes = root.into_value()?;
+        let root = MerkleNoteHash::read(root_bytes.as_ref()).map_err(to_napi_err)?;
+
+        self.transaction.set_anchor(root.0).map_err(to_napi_err)
+    }
+
+    /// Limit the number of memo bytes that outputs added after this call may
+    /// use. Outputs with longer memos are rejected.
+    #[napi]
+    pub fn set_max_memo_bytes(&mut self, max_memo_bytes: u32) {
+        self.transaction.set_max_memo_bytes(max_memo_bytes as usize);
     }
 
     /// Create a proof of a new note owned by the recipient in this transaction.
     #[napi]
     pub fn output(&mut self, note: &NativeNote) -> Result<()> {
-        self.transaction
-            .add_output(note.note.clone())
-            .map_err(to_napi_err)?;
+        self.add_output_note(note.note.clone())
+    }
+
+    /// Create a proof of a new note from its serialized bytes, as produced by
+    /// `Note.serialize()`, without constructing a `Note` first.
+    #[napi]
+    pub fn output_serialized(&mut self, note_bytes: JsBuffer) -> Result<()> {
+        let bytes = note_bytes.into_value()?;
+        let note = Note::read(bytes.as_ref()).map_err(to_napi_err)?;
+
+        self.add_output_note(note)
+    }
+
+    fn add_output_note(&mut self, note: Note) -> Result<()> {
+        self.check_asset_allowed(note.asset_id())?;
+
+        #[cfg(feature = "deterministic-rng")]
+        let note = match self.rng.as_mut() {
+            Some(rng) => Note::new_with_rng(
+                note.owner(),
+                note.value(),
+                note.memo(),
+                *note.asset_id(),
+                note.sender(),
+                rng,
+            ),
+            None => note,
+        };
+
+        self.transaction.add_output(note).map_err(to_napi_err)?;
 
         Ok(())
     }
 
+    /// Pad the transaction with zero value outputs to `spender_address` until
+    /// it has `target_output_count` outputs. Decoys are owned by the spender,
+    /// but each one increases the transaction size and therefore its fee.
+    /// Change is added after the decoys when posting.
+    #[napi]
+    pub fn add_decoy_outputs(
+        &mut self,
+        spender_address: String,
+        target_output_count: u32,
+    ) -> Result<()> {
+        let spender_address = PublicAddress::from_hex(&spender_address).map_err(to_napi_err)?;
+
+        self.check_asset_allowed(&NATIVE_ASSET)?;
+
+        self.transaction
+            .add_decoy_outputs(spender_address, target_output_count as usize)
+            .map_err(to_napi_err)
+    }
+
     /// Spend the note owned by spender_hex_key at the given witness location.
     #[napi]
     pub fn spend(&mut self, env: Env, note: &NativeNote, witness: Object) -> Result<()> {
@@ -213,6 +1034,61 @@ impl NativeTransaction {
         Ok(())
     }
 
+    /// Spend the note using a witness built on the Rust side with
+    /// `NativeWitness.fromCompressed`.
+    #[napi]
+    pub fn spend_compressed(&mut self, note: &NativeNote, witness: &NativeWitness) -> Result<()> {
+        self.transaction
+            .add_spend(note.note.clone(), &witness.witness)
+            .map_err(to_napi_err)?;
+
+        Ok(())
+    }
+
+    /// Not supported: always throws. Every spend in a transaction is
+    /// authorized by the single key the transaction is posted with, and the
+    /// fee is paid from the combined balance of all spends, so a spend from a
+    /// separate fee payer can neither be signed nor attributed to the fee.
+    #[allow(unused_variables)]
+    #[napi]
+    pub fn add_fee_payer_spend(
+        &mut self,
+        note: &NativeNote,
+        witness: Object,
+        spender_hex_key: String,
+    ) -> Result<()> {
+        Err(to_napi_err(
+            "Fee payer spends are not supported: all spends must be authorized by the transaction's spender",
+        ))
+    }
+
+    /// The distinct root hashes of the given witnesses, in the order they
+    /// were first seen. Witnesses for the spends of one transaction should
+    /// share a single anchor, so more than one root usually means that one of
+    /// the witnesses is stale.
+    #[napi]
+    pub fn validate_witness_roots(&self, env: Env, witnesses: Vec<Object>) -> Result<Vec<Buffer>> {
+        let mut roots: Vec<Buffer> = vec![];
+
+        for witness in witnesses {
+            let w = JsWitness {
+                cx: RefCell::new(env),
+                obj: witness,
+            };
+
+            let mut root_hash: Vec<u8> = vec![];
+            MerkleNoteHash::new(w.root_hash())
+                .write(&mut root_hash)
+                .map_err(to_napi_err)?;
+
+            if !roots.iter().any(|root| root.as_ref() == &root_hash[..]) {
+                roots.push(Buffer::from(root_hash));
+            }
+        }
+
+        Ok(roots)
+    }
+
     /// Mint a new asset with a given value as part of this transaction.
     #[napi]
     pub fn mint(
@@ -221,6 +1097,8 @@ impl NativeTransaction {
         value: BigInt,
         transfer_ownership_to: Option<&str>,
     ) -> Result<()> {
+        self.check_asset_allowed(asset.asset.id())?;
+
         let value_u64 = value.get_u64().1;
         match transfer_ownership_to {
             None => self
@@ -244,6 +1122,8 @@ impl NativeTransaction {
         let asset_id_bytes = asset_id_js_bytes.into_value()?;
         let asset_id = AssetIdentifier::new(asset_id_bytes.as_ref().try_into().unwrap())
             .map_err(to_napi_err)?;
+        self.check_asset_allowed(&asset_id)?;
+
         let value_u64 = value.get_u64().1;
         self.transaction
             .add_burn(asset_id, value_u64)
@@ -252,6 +1132,77 @@ impl NativeTransaction {
         Ok(())
     }
 
+    /// Map the hex identifier of every asset that cannot be balanced after
+    /// paying the intended fee to its (negative) balance, which is the amount
+    /// missing from the spends. Empty if the transaction can be posted.
+    /// Surpluses are not reported since they are returned as change.
+    #[napi]
+    pub fn check_balanced(
+        &self,
+        intended_transaction_fee: BigInt,
+    ) -> Result<HashMap<String, BigInt>> {
+        let intended_transaction_fee_i64: i64 = bigint_to_u64(&intended_transaction_fee)?
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        Ok(self
+            .transaction
+            .unbalanced_assets(intended_transaction_fee_i64)
+            .into_iter()
+            .map(|(asset_id, balance)| (bytes_to_hex(asset_id.as_bytes()), BigInt::from(balance)))
+            .collect())
+    }
+
+    /// Map the hex identifier of every burned asset to whether the amount
+    /// burned exceeds the value of that asset being spent.
+    #[napi]
+    pub fn burn_exceeds_spends(&self) -> HashMap<String, bool> {
+        self.transaction
+            .burn_exceeds_spends()
+            .into_iter()
+            .map(|(asset_id, exceeds)| (bytes_to_hex(asset_id.as_bytes()), exceeds))
+            .collect()
+    }
+
+    /// The change note that posting with the given fee would create for the
+    /// asset, or null if the asset has no change. Change goes to the spender.
+    /// The note matches the posted change note in everything but its
+    /// randomness.
+    #[napi]
+    pub fn build_change_note(
+        &self,
+        spender_hex_key: String,
+        intended_transaction_fee: BigInt,
+        asset_id: JsBuffer,
+    ) -> Result<Option<NativeNote>> {
+        let spender_key = SaplingKey::from_hex(&spender_hex_key).map_err(to_napi_err)?;
+
+        let intended_transaction_fee_i64: i64 = bigint_to_u64(&intended_transaction_fee)?
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        let asset_id_bytes = asset_id.into_value()?;
+        let asset_id = AssetIdentifier::new(
+            asset_id_bytes
+                .as_ref()
+                .try_into()
+                .map_err(|_| to_napi_err("Invalid asset identifier length"))?,
+        )
+        .map_err(to_napi_err)?;
+
+        let note = self
+            .transaction
+            .change_note(
+                &asset_id,
+                None,
+                spender_key.public_address(),
+                intended_transaction_fee_i64,
+            )
+            .map_err(to_napi_err)?;
+
+        Ok(note.map(|note| NativeNote { note }))
+    }
+
     /// Special case for posting a miners fee transaction. Miner fee transactions
     /// are unique in that they generate currency. They do not have any spends
     /// or change and therefore have a negative transaction fee. In normal use,
@@ -270,6 +1221,26 @@ impl NativeTransaction {
         Ok(Buffer::from(vec))
     }
 
+    /// Post a miners fee transaction paying out the given reward note, rather
+    /// than one added with `output`. Used to generate reproducible fixtures.
+    /// The note must be for the native asset.
+    #[napi]
+    pub fn post_miners_fee_with_note(
+        &mut self,
+        note: &NativeNote,
+        spender_hex_key: String,
+    ) -> Result<Buffer> {
+        let spender_key = SaplingKey::from_hex(&spender_hex_key).map_err(to_napi_err)?;
+        let transaction = self
+            .transaction
+            .post_miners_fee_with_note(note.note.clone(), &spender_key)
+            .map_err(to_napi_err)?;
+
+        let mut vec: Vec<u8> = vec![];
+        transaction.write(&mut vec).map_err(to_napi_err)?;
+        Ok(Buffer::from(vec))
+    }
+
     /// Used to generate invalid miners fee transactions for testing. Call
     /// post_miners_fee instead in user-facing code.
     #[napi(js_name = "_postMinersFeeUnchecked")]
@@ -295,15 +1266,28 @@ impl NativeTransaction {
     ///
     /// sum(spends) - sum(outputs) - intended_transaction_fee - change = 0
     /// aka: self.value_balance - intended_transaction_fee - change = 0
+    ///
+    /// Outputs in the posted transaction keep the order in which they were
+    /// added, with any change notes appended after them.
+    ///
+    /// If reject_zero_value_outputs is set, posting fails when any output has
+    /// a zero value.
     #[napi]
     pub fn post(
         &mut self,
         spender_hex_key: String,
         change_goes_to: Option<String>,
         intended_transaction_fee: BigInt,
+        reject_zero_value_outputs: Option<bool>,
     ) -> Result<Buffer> {
         let spender_key = SaplingKey::from_hex(&spender_hex_key).map_err(to_napi_err)?;
 
+        if reject_zero_value_outputs.unwrap_or(false) {
+            self.transaction
+                .check_no_zero_value_outputs()
+                .map_err(to_napi_err)?;
+        }
+
         let intended_transaction_fee_u64 = intended_transaction_fee.get_u64().1;
 
         let change_key = match change_goes_to {
@@ -319,12 +1303,126 @@ impl NativeTransaction {
         let mut vec: Vec<u8> = vec![];
         posted_transaction.write(&mut vec).map_err(to_napi_err)?;
 
-        Ok(Buffer::from(vec))
+        Ok(Buffer::from(vec))
+    }
+
+    /// Post the transaction like `post`, also returning the index of the
+    /// output holding the native asset change, or -1 if there is no change.
+    #[napi]
+    pub fn post_tracked(
+        &mut self,
+        spender_hex_key: String,
+        change_goes_to: Option<String>,
+        intended_transaction_fee: BigInt,
+    ) -> Result<NativeTrackedPost> {
+        let spender_key = SaplingKey::from_hex(&spender_hex_key).map_err(to_napi_err)?;
+
+        let intended_transaction_fee_u64 = bigint_to_u64(&intended_transaction_fee)?;
+
+        let change_key = match change_goes_to {
+            Some(address) => Some(PublicAddress::from_hex(&address).map_err(to_napi_err)?),
+            None => None,
+        };
+
+        let (posted_transaction, change_index) = self
+            .transaction
+            .post_tracked(&spender_key, change_key, intended_transaction_fee_u64)
+            .map_err(to_napi_err)?;
+
+        let mut vec: Vec<u8> = vec![];
+        posted_transaction.write(&mut vec).map_err(to_napi_err)?;
+
+        let change_index = match change_index {
+            Some(index) => index.try_into().map_err(to_napi_err)?,
+            None => -1,
+        };
+
+        Ok(NativeTrackedPost {
+            transaction: Buffer::from(vec),
+            change_index,
+        })
+    }
+
+    /// Post the transaction like `post`, failing if the serialized posted
+    /// transaction is larger than `max_bytes`. The size is only known after
+    /// proving, so an oversized transaction still costs a full post.
+    #[napi]
+    pub fn post_with_max_size(
+        &mut self,
+        spender_hex_key: String,
+        change_goes_to: Option<String>,
+        intended_transaction_fee: BigInt,
+        max_bytes: u32,
+    ) -> Result<Buffer> {
+        let posted = self.post(
+            spender_hex_key,
+            change_goes_to,
+            intended_transaction_fee,
+            None,
+        )?;
+
+        if posted.len() > max_bytes as usize {
+            return Err(to_napi_err(format!(
+                "Transaction size of {} bytes exceeds the maximum of {} bytes",
+                posted.len(),
+                max_bytes
+            )));
+        }
+
+        Ok(posted)
+    }
+
+    // Outputs buffer of an unsigned transaction
+    #[napi]
+    pub fn build(
+        &mut self,
+        proof_generation_key_str: String,
+        view_key_str: String,
+        outgoing_view_key_str: String,
+        public_address_str: String,
+        intended_transaction_fee: BigInt,
+        change_goes_to: Option<String>,
+    ) -> Result<Buffer> {
+        let unsigned_transaction = self.build_unsigned_transaction(
+            proof_generation_key_str,
+            view_key_str,
+            outgoing_view_key_str,
+            public_address_str,
+            intended_transaction_fee,
+            change_goes_to,
+        )?;
+
+        let mut vec: Vec<u8> = vec![];
+        unsigned_transaction.write(&mut vec).map_err(to_napi_err)?;
+
+        Ok(Buffer::from(vec))
+    }
+
+    /// Build the transaction like `build`, returning an `UnsignedTransaction`
+    /// directly rather than its serialized bytes.
+    #[napi]
+    pub fn build_unsigned(
+        &mut self,
+        proof_generation_key_str: String,
+        view_key_str: String,
+        outgoing_view_key_str: String,
+        public_address_str: String,
+        intended_transaction_fee: BigInt,
+        change_goes_to: Option<String>,
+    ) -> Result<NativeUnsignedTransaction> {
+        let transaction = self.build_unsigned_transaction(
+            proof_generation_key_str,
+            view_key_str,
+            outgoing_view_key_str,
+            public_address_str,
+            intended_transaction_fee,
+            change_goes_to,
+        )?;
+
+        Ok(NativeUnsignedTransaction { transaction })
     }
 
-    // Outputs buffer of an unsigned transaction
-    #[napi]
-    pub fn build(
+    fn build_unsigned_transaction(
         &mut self,
         proof_generation_key_str: String,
         view_key_str: String,
@@ -332,7 +1430,7 @@ impl NativeTransaction {
         public_address_str: String,
         intended_transaction_fee: BigInt,
         change_goes_to: Option<String>,
-    ) -> Result<Buffer> {
+    ) -> Result<UnsignedTransaction> {
         let view_key = ViewKey::from_hex(&view_key_str).map_err(to_napi_err)?;
         let outgoing_view_key =
             OutgoingViewKey::from_hex(&outgoing_view_key_str).map_err(to_napi_err)?;
@@ -355,16 +1453,29 @@ impl NativeTransaction {
             )
             .map_err(to_napi_err)?;
 
-        let mut vec: Vec<u8> = vec![];
-        unsigned_transaction.write(&mut vec).map_err(to_napi_err)?;
-
-        Ok(Buffer::from(vec))
+        Ok(unsigned_transaction)
     }
 
     #[napi]
     pub fn set_expiration(&mut self, sequence: u32) -> Undefined {
         self.transaction.set_expiration(sequence);
     }
+
+    /// Set the transaction to expire `ttl` blocks after `current_sequence`.
+    /// Throws if the expiration sequence would overflow.
+    #[napi]
+    pub fn set_expiration_ttl(&mut self, current_sequence: u32, ttl: u32) -> Result<()> {
+        self.transaction
+            .set_expiration_ttl(current_sequence, ttl)
+            .map_err(to_napi_err)
+    }
+
+    /// Clear the pending spends and outputs, and the note material they hold,
+    /// from memory. The transaction is unusable after this is called.
+    #[napi]
+    pub fn zeroize(&mut self) -> Undefined {
+        self.transaction.zeroize();
+    }
 }
 
 #[napi]
@@ -382,6 +1493,527 @@ pub fn verify_transactions(serialized_transactions: Vec<JsBuffer>) -> Result<boo
     Ok(batch_verify_transactions(transactions.iter()).is_ok())
 }
 
+/// Verify the serialized transactions like `verifyTransactions`, also
+/// returning the milliseconds spent in each phase: deserializing, checking
+/// the signatures (including the binding signature), and batch verifying the
+/// proofs. Verification stops at the first failing phase, and phases that
+/// did not run report 0.
+#[napi]
+pub fn verify_transactions_timed(
+    serialized_transactions: Vec<JsBuffer>,
+) -> Result<NativeVerificationTimings> {
+    let mut timings = NativeVerificationTimings {
+        ok: false,
+        parse_ms: 0.0,
+        proof_ms: 0.0,
+        binding_ms: 0.0,
+    };
+
+    let start = Instant::now();
+    let mut transactions: Vec<Transaction> = Vec::with_capacity(serialized_transactions.len());
+    for tx_bytes in serialized_transactions {
+        let buf = tx_bytes.into_value()?;
+        match Transaction::read(buf.as_ref()) {
+            Ok(tx) => transactions.push(tx),
+            Err(_) => {
+                timings.parse_ms = start.elapsed().as_secs_f64() * 1000.0;
+                return Ok(timings);
+            }
+        }
+    }
+    timings.parse_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    let start = Instant::now();
+    let signatures_valid = transactions
+        .iter()
+        .all(|tx| verify_transaction_signatures(tx).is_ok());
+    timings.binding_ms = start.elapsed().as_secs_f64() * 1000.0;
+    if !signatures_valid {
+        return Ok(timings);
+    }
+
+    let start = Instant::now();
+    timings.ok = batch_verify_transaction_proofs(transactions.iter()).is_ok();
+    timings.proof_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+    Ok(timings)
+}
+
+/// Verify the serialized transactions in chunks of `chunk_size`, so that only
+/// one chunk of deserialized transactions is held in memory at a time.
+///
+/// Returns the index of the first transaction that could not be deserialized
+/// or failed verification, or null if every transaction is valid.
+#[napi]
+pub fn verify_transactions_streaming(
+    serialized_transactions: Vec<JsBuffer>,
+    chunk_size: u32,
+) -> Result<Option<u32>> {
+    if chunk_size == 0 {
+        return Err(to_napi_err("Chunk size must be greater than 0"));
+    }
+    let chunk_size = chunk_size as usize;
+
+    let mut offset = 0;
+    let mut remaining = serialized_transactions.into_iter().peekable();
+
+    while remaining.peek().is_some() {
+        let mut transactions: Vec<Transaction> = Vec::with_capacity(chunk_size);
+        let mut read_failure = None;
+
+        for tx_bytes in remaining.by_ref().take(chunk_size) {
+            let buf = tx_bytes.into_value()?;
+            match Transaction::read(buf.as_ref()) {
+                Ok(tx) => transactions.push(tx),
+                Err(_) => {
+                    read_failure = Some(offset + transactions.len());
+                    break;
+                }
+            }
+        }
+
+        if let Err(err) = batch_verify_transactions(transactions.iter()) {
+            // The batch only tells us that something failed, so fall back to
+            // verifying one at a time to find the first invalid transaction
+            let index = transactions
+                .iter()
+                .position(|tx| verify_transaction(tx).is_err())
+                .ok_or_else(|| to_napi_err(err))?;
+            let index: u32 = (offset + index)
+                .try_into()
+                .map_err(|_| to_napi_err("Value out of range"))?;
+            return Ok(Some(index));
+        }
+
+        if let Some(index) = read_failure {
+            let index: u32 = index
+                .try_into()
+                .map_err(|_| to_napi_err("Value out of range"))?;
+            return Ok(Some(index));
+        }
+
+        offset += transactions.len();
+    }
+
+    Ok(None)
+}
+
+/// The hex public addresses of the owners of every mint across the serialized
+/// transactions, such as all the transactions in a block. Each address is
+/// listed once, in the order it was first seen.
+#[napi]
+pub fn mint_owners(serialized_transactions: Vec<JsBuffer>) -> Result<Vec<String>> {
+    let mut seen: HashSet<[u8; 32]> = HashSet::new();
+    let mut owners: Vec<String> = vec![];
+
+    for tx_bytes in serialized_transactions {
+        let buf = tx_bytes.into_value()?;
+        let transaction = Transaction::read(buf.as_ref()).map_err(to_napi_err)?;
+
+        for mint in transaction.mints() {
+            if seen.insert(mint.owner.public_address()) {
+                owners.push(mint.owner.hex_public_address());
+            }
+        }
+    }
+
+    Ok(owners)
+}
+
+/// Build a child transaction that spends the native asset change note of
+/// `original`, pays `additional_fee` to the miner, and returns the remainder
+/// to the spender.
+///
+/// This assumes that the change of `original` went to the spender's own
+/// address, and that the change note has already been added to the note tree.
+/// Notes can only be spent with a witness against a known anchor, so
+/// `change_witness` must be a witness for that change note, and the child
+/// cannot be mined before `original` is.
+#[napi]
+pub fn bump_fee(
+    env: Env,
+    original: JsBuffer,
+    additional_fee: BigInt,
+    spender_hex_key: String,
+    change_witness: Object,
+) -> Result<Buffer> {
+    let spender_key = SaplingKey::from_hex(&spender_hex_key).map_err(to_napi_err)?;
+
+    let original_bytes = original.into_value()?;
+    let original = Transaction::read(original_bytes.as_ref()).map_err(to_napi_err)?;
+
+    // Change notes are appended after the user outputs, so search backwards
+    let change_note = original
+        .outputs()
+        .iter()
+        .rev()
+        .filter_map(|output| {
+            output
+                .merkle_note()
+                .decrypt_note_for_owner(spender_key.incoming_view_key())
+                .ok()
+        })
+        .find(|note| {
+            note.asset_id() == &NATIVE_ASSET && note.sender() == spender_key.public_address()
+        })
+        .ok_or_else(|| to_napi_err("Transaction has no native asset change for the spender"))?;
+
+    let witness = JsWitness {
+        cx: RefCell::new(env),
+        obj: change_witness,
+    };
+
+    let mut transaction = ProposedTransaction::new(original.version());
+    transaction
+        .add_spend(change_note, &witness)
+        .map_err(to_napi_err)?;
+
+    let posted_transaction = transaction
+        .post(&spender_key, None, bigint_to_u64(&additional_fee)?)
+        .map_err(to_napi_err)?;
+
+    let mut vec: Vec<u8> = vec![];
+    posted_transaction.write(&mut vec).map_err(to_napi_err)?;
+
+    Ok(Buffer::from(vec))
+}
+
+/// The sum of the fees paid by the serialized transactions, i.e. the fees a
+/// block producer collects. Miners fee transactions, which have a negative
+/// fee, are skipped.
+#[napi]
+pub fn total_fees(serialized_transactions: Vec<JsBuffer>) -> Result<BigInt> {
+    let mut total: u64 = 0;
+
+    for tx_bytes in serialized_transactions {
+        let buf = tx_bytes.into_value()?;
+        let transaction = Transaction::read(buf.as_ref()).map_err(to_napi_err)?;
+
+        if transaction.fee() > 0 {
+            total = total
+                .checked_add(transaction.fee() as u64)
+                .ok_or_else(|| to_napi_err("Value out of range"))?;
+        }
+    }
+
+    Ok(BigInt::from(total))
+}
+
+/// Returns true if the buffer parses as exactly one serialized transaction.
+/// Only the structure is checked; proofs and signatures are not verified.
+#[napi]
+pub fn is_valid_transaction_encoding(js_bytes: JsBuffer) -> Result<bool> {
+    let bytes = js_bytes.into_value()?;
+    let mut remaining: &[u8] = bytes.as_ref();
+
+    Ok(Transaction::read(&mut remaining).is_ok() && remaining.is_empty())
+}
+
+/// Returns true if the buffer is the canonical encoding of a transaction, i.e.
+/// it parses and serializes back to exactly the same bytes. Encodings that
+/// fail to parse are not canonical.
+#[napi]
+pub fn is_canonical(serialized: JsBuffer) -> Result<bool> {
+    let bytes = serialized.into_value()?;
+    let bytes: &[u8] = bytes.as_ref();
+
+    let transaction = match Transaction::read(bytes) {
+        Ok(transaction) => transaction,
+        Err(_) => return Ok(false),
+    };
+
+    let mut reserialized: Vec<u8> = Vec::with_capacity(bytes.len());
+    transaction.write(&mut reserialized).map_err(to_napi_err)?;
+
+    Ok(reserialized == bytes)
+}
+
+/// Classify a serialized transaction as "posted" if it is a fully signed
+/// transaction or "unsigned" if it still needs to be signed, such as with
+/// FROST. Throws if the buffer is neither.
+#[napi]
+pub fn transaction_kind(buffer: JsBuffer) -> Result<String> {
+    let bytes = buffer.into_value()?;
+
+    let mut remaining: &[u8] = bytes.as_ref();
+    if Transaction::read(&mut remaining).is_ok() && remaining.is_empty() {
+        return Ok("posted".to_string());
+    }
+
+    let mut remaining: &[u8] = bytes.as_ref();
+    if UnsignedTransaction::read(&mut remaining).is_ok() && remaining.is_empty() {
+        return Ok("unsigned".to_string());
+    }
+
+    Err(to_napi_err("Buffer is not a serialized transaction"))
+}
+
+/// The id of the serialized transaction, i.e. its signature hash. Equivalent to
+/// `new TransactionPosted(serialized).hash()`.
+#[napi]
+pub fn transaction_id(serialized_transaction: JsBuffer) -> Result<Buffer> {
+    let bytes = serialized_transaction.into_value()?;
+    let transaction = Transaction::read(bytes.as_ref()).map_err(to_napi_err)?;
+
+    let hash = transaction
+        .transaction_signature_hash()
+        .map_err(to_napi_err)?;
+
+    Ok(Buffer::from(hash.as_ref()))
+}
+
+/// The smallest fee that pays at least `rate` per byte of the serialized
+/// transaction. The fee is a fixed size field, so setting it does not by
+/// itself change the size of the transaction.
+#[napi]
+pub fn min_fee_for_rate(serialized_transaction: JsBuffer, rate: BigInt) -> Result<BigInt> {
+    let bytes = serialized_transaction.into_value()?;
+
+    let rate = bigint_to_u64(&rate)?;
+
+    Ok(BigInt::from(bytes.len() as u128 * rate as u128))
+}
+
+/// Returns true if the binding signature of the serialized transaction is valid
+/// under the given verifying key.
+#[napi]
+pub fn verify_signature(serialized_transaction: JsBuffer, verifying_key: Buffer) -> Result<bool> {
+    let bytes = serialized_transaction.into_value()?;
+    let transaction = Transaction::read(bytes.as_ref()).map_err(to_napi_err)?;
+
+    let verifying_key = redjubjub::PublicKey::read(verifying_key.as_ref()).map_err(to_napi_err)?;
+
+    Ok(transaction
+        .verify_binding_signature_with_key(&verifying_key)
+        .is_ok())
+}
+
+/// Returns true if the serialized transaction carries a valid FROST signature
+/// from the group in the public key package. `public_key_randomness` is the
+/// hex randomness of the unsigned transaction that was signed, used to
+/// reconstruct the randomized group verifying key. Proofs are not verified.
+#[napi]
+pub fn verify_frost_signature(
+    serialized_transaction: JsBuffer,
+    public_key_package_str: String,
+    public_key_randomness: String,
+) -> Result<bool> {
+    let bytes = serialized_transaction.into_value()?;
+    let transaction = Transaction::read(bytes.as_ref()).map_err(to_napi_err)?;
+
+    let public_key_package = PublicKeyPackage::deserialize(
+        &hex_to_vec_bytes(&public_key_package_str).map_err(to_napi_err)?,
+    )
+    .map_err(to_napi_err)?;
+
+    let randomizer =
+        Randomizer::deserialize(&hex_to_bytes(&public_key_randomness).map_err(to_napi_err)?)
+            .map_err(to_napi_err)?;
+
+    Ok(transaction
+        .verify_frost_signature(&public_key_package, randomizer)
+        .is_ok())
+}
+
+/// Map the hex identifier of every asset minted or burned by either serialized
+/// transaction to the change in its effects when `b` replaces `a`: the
+/// difference in amount minted, in amount burned, and in net supply change
+/// (minted minus burned). Each difference is `b`'s value minus `a`'s, and
+/// assets whose effects are the same in both are omitted.
+#[napi]
+pub fn asset_effect_diff(a: JsBuffer, b: JsBuffer) -> Result<HashMap<String, NativeAssetEffect>> {
+    let a_bytes = a.into_value()?;
+    let a = Transaction::read(a_bytes.as_ref()).map_err(to_napi_err)?;
+    let b_bytes = b.into_value()?;
+    let b = Transaction::read(b_bytes.as_ref()).map_err(to_napi_err)?;
+
+    let mut diff: HashMap<AssetIdentifier, (i128, i128)> = HashMap::new();
+    for (transaction, sign) in [(&a, -1), (&b, 1)] {
+        for mint in transaction.mints() {
+            diff.entry(mint.asset.id()).or_insert((0, 0)).0 += sign * mint.value as i128;
+        }
+        for burn in transaction.burns() {
+            diff.entry(burn.asset_id).or_insert((0, 0)).1 += sign * burn.value as i128;
+        }
+    }
+
+    Ok(diff
+        .into_iter()
+        .filter(|(_, (minted, burned))| *minted != 0 || *burned != 0)
+        .map(|(asset_id, (minted, burned))| {
+            (
+                bytes_to_hex(asset_id.as_bytes()),
+                NativeAssetEffect {
+                    minted: BigInt::from(minted),
+                    burned: BigInt::from(burned),
+                    net: BigInt::from(minted - burned),
+                },
+            )
+        })
+        .collect())
+}
+
+/// Build and post a transaction that spends every given note to a single
+/// output for `destination`, returning the serialized posted transaction.
+/// Notes are passed serialized, each with the witness at the same index, and
+/// must all be for `asset_id`. The fee is taken out of the swept value, so
+/// only sweeps of the native asset can pay a non-zero fee.
+#[napi]
+pub fn sweep(
+    env: Env,
+    notes: Vec<JsBuffer>,
+    witnesses: Vec<Object>,
+    asset_id: JsBuffer,
+    destination: String,
+    spender_hex_key: String,
+    intended_transaction_fee: BigInt,
+) -> Result<Buffer> {
+    if notes.is_empty() {
+        return Err(to_napi_err("No notes to sweep"));
+    }
+    if notes.len() != witnesses.len() {
+        return Err(to_napi_err("Each note must have exactly one witness"));
+    }
+
+    let spender_key = SaplingKey::from_hex(&spender_hex_key).map_err(to_napi_err)?;
+    let destination = PublicAddress::from_hex(&destination).map_err(to_napi_err)?;
+    let intended_transaction_fee_u64 = bigint_to_u64(&intended_transaction_fee)?;
+
+    let asset_id_bytes = asset_id.into_value()?;
+    let asset_id = AssetIdentifier::new(
+        asset_id_bytes
+            .as_ref()
+            .try_into()
+            .map_err(|_| to_napi_err("Invalid asset identifier length"))?,
+    )
+    .map_err(to_napi_err)?;
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    let mut total: u64 = 0;
+    for (index, (note_bytes, witness)) in notes.into_iter().zip(witnesses).enumerate() {
+        let note_bytes = note_bytes.into_value()?;
+        let note = Note::read(note_bytes.as_ref()).map_err(to_napi_err)?;
+
+        if note.asset_id() != &asset_id {
+            return Err(to_napi_err(format!(
+                "Note {} is for asset {}, not {}",
+                index,
+                bytes_to_hex(note.asset_id().as_bytes()),
+                bytes_to_hex(asset_id.as_bytes())
+            )));
+        }
+
+        total = total
+            .checked_add(note.value())
+            .ok_or_else(|| to_napi_err("Value out of range"))?;
+
+        let w = JsWitness {
+            cx: RefCell::new(env),
+            obj: witness,
+        };
+        transaction.add_spend(note, &w).map_err(to_napi_err)?;
+    }
+
+    let swept_value = match asset_id == NATIVE_ASSET {
+        true => total
+            .checked_sub(intended_transaction_fee_u64)
+            .ok_or_else(|| to_napi_err("Fee exceeds the swept value"))?,
+        false => total,
+    };
+
+    let output = Note::new(
+        destination,
+        swept_value,
+        "",
+        asset_id,
+        spender_key.public_address(),
+    );
+    transaction.add_output(output).map_err(to_napi_err)?;
+
+    let posted_transaction = transaction
+        .post(&spender_key, None, intended_transaction_fee_u64)
+        .map_err(to_napi_err)?;
+
+    let mut vec: Vec<u8> = vec![];
+    posted_transaction.write(&mut vec).map_err(to_napi_err)?;
+
+    Ok(Buffer::from(vec))
+}
+
+/// Returns the indices of the serialized transactions that have expired as of
+/// `current_sequence`. Only the expiration field of each transaction is read.
+/// An expiration of 0 means the transaction never expires.
+#[napi]
+pub fn filter_expired(
+    serialized_transactions: Vec<JsBuffer>,
+    current_sequence: u32,
+) -> Result<Vec<u32>> {
+    let mut expired = vec![];
+
+    for (index, tx_bytes) in serialized_transactions.into_iter().enumerate() {
+        let buf = tx_bytes.into_value()?;
+        let expiration = Transaction::read_expiration(buf.as_ref()).map_err(to_napi_err)?;
+
+        if expiration != 0 && expiration <= current_sequence {
+            expired.push(index as u32);
+        }
+    }
+
+    Ok(expired)
+}
+
+/// Returns true if the two serialized transactions spend at least one of the
+/// same notes, i.e. they share a nullifier and cannot both be mined.
+#[napi]
+pub fn transactions_conflict(a: JsBuffer, b: JsBuffer) -> Result<bool> {
+    let a_bytes = a.into_value()?;
+    let b_bytes = b.into_value()?;
+
+    let a_transaction = Transaction::read(a_bytes.as_ref()).map_err(to_napi_err)?;
+    let b_transaction = Transaction::read(b_bytes.as_ref()).map_err(to_napi_err)?;
+
+    let nullifiers: HashSet<[u8; 32]> = a_transaction
+        .spends()
+        .iter()
+        .map(|spend| spend.nullifier().0)
+        .collect();
+
+    Ok(b_transaction
+        .spends()
+        .iter()
+        .any(|spend| nullifiers.contains(&spend.nullifier().0)))
+}
+
+/// Returns true if the two proposed transactions have the same spends,
+/// outputs, mints, burns, and expiration, ignoring the randomness chosen for
+/// each proof and output note.
+#[napi]
+pub fn proposed_equivalent(a: &NativeTransaction, b: &NativeTransaction) -> bool {
+    a.transaction.equivalent(&b.transaction)
+}
+
+#[cfg(feature = "deterministic-rng")]
+#[napi]
+impl NativeTransaction {
+    /// Seed the rng used for the randomness of notes passed to `output` after
+    /// this call, so that their commitments are deterministic. Each output
+    /// note is rebuilt with randomness drawn from the seeded rng. The seed
+    /// must be 32 bytes. Only available with the `deterministic-rng` feature,
+    /// for tests.
+    #[napi]
+    pub fn seed_rng(&mut self, seed: JsBuffer) -> Result<()> {
+        let seed_bytes = seed.into_value()?;
+        let seed: [u8; 32] = seed_bytes
+            .as_ref()
+            .try_into()
+            .map_err(|_| to_napi_err("Invalid seed length"))?;
+
+        self.rng = Some(StdRng::from_seed(seed));
+
+        Ok(())
+    }
+}
+
 #[napi(js_name = "UnsignedTransaction")]
 pub struct NativeUnsignedTransaction {
     transaction: UnsignedTransaction,
@@ -406,12 +2038,180 @@ impl NativeUnsignedTransaction {
         Ok(Buffer::from(vec))
     }
 
+    /// The canonical bytes covered by the transaction's signatures, excluding
+    /// the signatures themselves. Their Blake2b hash, personalized with
+    /// `IFsighsh`, is the hash that signers sign.
+    #[napi]
+    pub fn signable_bytes(&self) -> Result<Buffer> {
+        let bytes = self
+            .transaction
+            .signature_hash_preimage()
+            .map_err(to_napi_err)?;
+
+        Ok(Buffer::from(bytes))
+    }
+
     #[napi]
     pub fn public_key_randomness(&self) -> String {
         let bytes = self.transaction.public_key_randomness().to_bytes();
         bytes_to_hex(&bytes)
     }
 
+    /// A JSON description of the transaction for display on a signing
+    /// device. Keys are always written in the same order, without whitespace,
+    /// and amounts are decimal strings, so the same transaction always yields
+    /// the same bytes. Output values are only included when an outgoing view
+    /// key that can decrypt the output is given.
+    #[napi]
+    pub fn to_display_json(&self, outgoing_hex_key: Option<String>) -> Result<String> {
+        let outgoing_view_key = outgoing_hex_key
+            .map(|key| OutgoingViewKey::from_hex(&key))
+            .transpose()
+            .map_err(to_napi_err)?;
+
+        let spends: Vec<String> = self
+            .transaction
+            .spends()
+            .iter()
+            .map(|spend| {
+                format!(
+                    "{{\"nullifier\":\"{}\"}}",
+                    bytes_to_hex(&spend.description().nullifier().0)
+                )
+            })
+            .collect();
+
+        let outputs: Vec<String> = self
+            .transaction
+            .outputs()
+            .iter()
+            .map(|output| {
+                let commitment = bytes_to_hex(&output.merkle_note().merkle_hash().0.to_bytes_le());
+                let note = outgoing_view_key.as_ref().and_then(|key| {
+                    output.merkle_note().decrypt_note_for_spender(key).ok()
+                });
+                match note {
+                    Some(note) => format!(
+                        "{{\"commitment\":\"{}\",\"owner\":\"{}\",\"value\":\"{}\",\"assetId\":\"{}\"}}",
+                        commitment,
+                        note.owner().hex_public_address(),
+                        note.value(),
+                        bytes_to_hex(note.asset_id().as_bytes())
+                    ),
+                    None => format!("{{\"commitment\":\"{}\"}}", commitment),
+                }
+            })
+            .collect();
+
+        let mints: Vec<String> = self
+            .transaction
+            .mints()
+            .iter()
+            .map(|mint| {
+                let mint = mint.description();
+                format!(
+                    "{{\"assetId\":\"{}\",\"value\":\"{}\",\"owner\":\"{}\"}}",
+                    bytes_to_hex(mint.asset.id().as_bytes()),
+                    mint.value,
+                    mint.owner.hex_public_address()
+                )
+            })
+            .collect();
+
+        let burns: Vec<String> = self
+            .transaction
+            .burns()
+            .iter()
+            .map(|burn| {
+                format!(
+                    "{{\"assetId\":\"{}\",\"value\":\"{}\"}}",
+                    bytes_to_hex(burn.asset_id.as_bytes()),
+                    burn.value
+                )
+            })
+            .collect();
+
+        Ok(format!(
+            "{{\"spends\":[{}],\"outputs\":[{}],\"mints\":[{}],\"burns\":[{}],\"fee\":\"{}\",\"expiration\":{}}}",
+            spends.join(","),
+            outputs.join(","),
+            mints.join(","),
+            burns.join(","),
+            self.transaction.fee(),
+            self.transaction.expiration()
+        ))
+    }
+
+    /// Serialize this transaction together with the commitments and signature
+    /// shares collected for it so far, so that a coordinator can resume the
+    /// signing session after a restart with `importSession`. The blob holds
+    /// the length-prefixed transaction, the length-prefixed commitments as
+    /// written by `serializeCommitments`, and then the signature shares as
+    /// written by `serializeSignatureShares`.
+    #[napi]
+    pub fn export_session(
+        &self,
+        commitments: HashMap<String, NativeSigningCommitments>,
+        signature_shares: HashMap<String, String>,
+    ) -> Result<Buffer> {
+        let mut transaction: Vec<u8> = vec![];
+        self.transaction
+            .write(&mut transaction)
+            .map_err(to_napi_err)?;
+        let commitments = serialize_commitments(commitments)?;
+        let signature_shares = serialize_signature_shares(signature_shares)?;
+
+        let mut vec: Vec<u8> = vec![];
+        for section in [&transaction[..], commitments.as_ref()] {
+            let len: u32 = section
+                .len()
+                .try_into()
+                .map_err(|_| to_napi_err("Value out of range"))?;
+            vec.extend_from_slice(&len.to_le_bytes());
+            vec.extend_from_slice(section);
+        }
+        vec.extend_from_slice(signature_shares.as_ref());
+
+        Ok(Buffer::from(vec))
+    }
+
+    /// Restore a signing session written by `exportSession`. The transaction
+    /// is returned serialized, to be passed to the `UnsignedTransaction`
+    /// constructor.
+    #[napi]
+    pub fn import_session(js_bytes: JsBuffer) -> Result<NativeSigningSession> {
+        let bytes = js_bytes.into_value()?;
+        let mut remaining: &[u8] = bytes.as_ref();
+
+        let mut sections: Vec<&[u8]> = vec![];
+        for _ in 0..2 {
+            if remaining.len() < 4 {
+                return Err(to_napi_err("Invalid signing session length"));
+            }
+            let (len_bytes, rest) = remaining.split_at(4);
+            let len = u32::from_le_bytes(len_bytes.try_into().unwrap()) as usize;
+            if rest.len() < len {
+                return Err(to_napi_err("Invalid signing session length"));
+            }
+            let (section, rest) = rest.split_at(len);
+            sections.push(section);
+            remaining = rest;
+        }
+
+        // validate the transaction before handing it back
+        let mut transaction_bytes = sections[0];
+        UnsignedTransaction::read(&mut transaction_bytes).map_err(to_napi_err)?;
+        if !transaction_bytes.is_empty() {
+            return Err(to_napi_err("Invalid signing session length"));
+        }
+
+        Ok(NativeSigningSession {
+            transaction: Buffer::from(sections[0]),
+            commitments: read_commitments(sections[1])?,
+            signature_shares: read_signature_shares(remaining)?,
+        })
+    }
+
     #[napi]
     pub fn signing_package(
         &self,
@@ -447,6 +2247,24 @@ impl NativeUnsignedTransaction {
         ))
     }
 
+    /// Whether the signing package asks signers to sign this transaction's
+    /// signature hash. Check this before producing a signature share for a
+    /// package received from a coordinator.
+    #[napi]
+    pub fn matches_signing_package(&self, signing_package_str: String) -> Result<bool> {
+        let signing_package = SigningPackage::deserialize(
+            &hex_to_vec_bytes(&signing_package_str).map_err(to_napi_err)?,
+        )
+        .map_err(to_napi_err)?;
+
+        self.transaction
+            .matches_signing_package(&signing_package)
+            .map_err(to_napi_err)
+    }
+
+    /// Aggregate the signature shares and sign the transaction. There must be
+    /// exactly one share from each signer that committed to the signing
+    /// package.
     #[napi]
     pub fn sign_frost(
         &mut self,
@@ -469,7 +2287,15 @@ impl NativeUnsignedTransaction {
             let signature_share =
                 SignatureShare::deserialize(hex_to_bytes(v).map_err(to_napi_err)?)
                     .map_err(to_napi_err)?;
-            signature_shares.insert(identifier, signature_share);
+            if signature_shares
+                .insert(identifier, signature_share)
+                .is_some()
+            {
+                return Err(to_napi_err(format!(
+                    "Duplicate signature share for identifier {}",
+                    k
+                )));
+            }
         }
 
         let signed_transaction = self
@@ -482,4 +2308,95 @@ impl NativeUnsignedTransaction {
 
         Ok(Buffer::from(vec))
     }
+
+    /// Sign with the key package of a 1-of-1 FROST account, generating the
+    /// commitment, signing package, and signature share internally. Only
+    /// valid when the threshold is 1.
+    ///
+    /// **The seed must be 8 bytes of fresh randomness for every signature.**
+    /// The signing nonces are derived from it, and reusing nonces across
+    /// different transactions reveals the key share. The seed is mixed with
+    /// the transaction's signature hash as a safeguard, but that is not a
+    /// substitute for a random seed.
+    #[napi]
+    pub fn sign_frost_single(&mut self, key_package_hex: String, seed: JsBuffer) -> Result<Buffer> {
+        let key_package =
+            KeyPackage::deserialize(&hex_to_vec_bytes(&key_package_hex).map_err(to_napi_err)?)
+                .map_err(to_napi_err)?;
+
+        let seed_bytes = seed.into_value()?;
+        let seed = u64::from_le_bytes(
+            seed_bytes
+                .as_ref()
+                .try_into()
+                .map_err(|_| to_napi_err("Invalid seed length"))?,
+        );
+
+        let signed_transaction = self
+            .transaction
+            .sign_frost_single(&key_package, seed)
+            .map_err(to_napi_err)?;
+
+        let mut vec: Vec<u8> = vec![];
+        signed_transaction.write(&mut vec).map_err(to_napi_err)?;
+
+        Ok(Buffer::from(vec))
+    }
+}
+
+#[cfg(feature = "test-vectors")]
+#[napi]
+impl NativeUnsignedTransaction {
+    /// Assemble an unsigned transaction from its serialized parts rather than
+    /// building it from notes, for conformance tests with known descriptions.
+    /// Nothing is checked beyond the parts being well formed, so the result
+    /// may not balance or sign. Only available with the `test-vectors`
+    /// feature.
+    #[allow(clippy::too_many_arguments)]
+    #[napi(factory)]
+    pub fn from_components(
+        version: u8,
+        fee: BigInt,
+        expiration: u32,
+        randomized_public_key: JsBuffer,
+        public_key_randomness: JsBuffer,
+        spends: Vec<JsBuffer>,
+        outputs: Vec<JsBuffer>,
+        mints: Vec<JsBuffer>,
+        burns: Vec<JsBuffer>,
+        binding_signature: JsBuffer,
+    ) -> Result<NativeUnsignedTransaction> {
+        let (fee, lossless) = fee.get_i64();
+        if !lossless {
+            return Err(to_napi_err("Value out of range"));
+        }
+
+        // Assemble the serialized form and read it back, so the parts are
+        // validated exactly as they would be when deserializing
+        let mut bytes: Vec<u8> = vec![version];
+        for count in [spends.len(), outputs.len(), mints.len(), burns.len()] {
+            bytes.extend_from_slice(&(count as u64).to_le_bytes());
+        }
+        bytes.extend_from_slice(&fee.to_le_bytes());
+        bytes.extend_from_slice(&expiration.to_le_bytes());
+        bytes.extend_from_slice(randomized_public_key.into_value()?.as_ref());
+        bytes.extend_from_slice(public_key_randomness.into_value()?.as_ref());
+        for part in spends
+            .into_iter()
+            .chain(outputs)
+            .chain(mints)
+            .chain(burns)
+            .chain([binding_signature])
+        {
+            bytes.extend_from_slice(part.into_value()?.as_ref());
+        }
+
+        let mut remaining: &[u8] = bytes.as_ref();
+        let transaction = UnsignedTransaction::read(&mut remaining).map_err(to_napi_err)?;
+        if !remaining.is_empty() {
+            return Err(to_napi_err("Unexpected trailing bytes in components"));
+        }
+
+        Ok(NativeUnsignedTransaction { transaction })
+    }
 }
@@ -10,8 +10,14 @@ use ironfish::MerkleNoteHash;
 use napi::bindgen_prelude::*;
 use napi::Env;
 use napi::JsObject;
+use napi_derive::napi;
 
-use ironfish::witness::{WitnessNode, WitnessTrait};
+use ironfish::merkle_note::position;
+use ironfish::witness::{
+    witnesses_for_positions as compute_witnesses, Witness, WitnessNode, WitnessTrait,
+};
+
+use crate::{bigint_to_u64, to_napi_err};
 
 pub struct JsWitness {
     pub cx: RefCell<Env>,
@@ -116,3 +122,84 @@ impl WitnessTrait for JsWitness {
             .unwrap()
     }
 }
+
+/// The tree position of the note a witness is for, implied by the left and
+/// right sides of the nodes in its auth path.
+#[napi]
+pub fn witness_position(env: Env, witness: Object) -> BigInt {
+    let w = JsWitness {
+        cx: RefCell::new(env),
+        obj: witness,
+    };
+
+    BigInt::from(position(&w))
+}
+
+/// Compute witnesses for the notes at the given positions of a note commitment
+/// tree snapshot. The snapshot is the 32 byte commitment of every note in the
+/// tree, concatenated in position order.
+#[napi]
+pub fn witnesses_for_positions(
+    tree: JsBuffer,
+    positions: Vec<BigInt>,
+) -> Result<Vec<NativeWitness>> {
+    let tree_bytes = tree.into_value()?;
+    if tree_bytes.len() % 32 != 0 {
+        return Err(to_napi_err("Invalid note commitment tree length"));
+    }
+
+    let leaves = tree_bytes
+        .chunks(32)
+        .map(|leaf| MerkleNoteHash::read(leaf).map(|hash| hash.0))
+        .collect::<std::result::Result<Vec<Scalar>, _>>()
+        .map_err(to_napi_err)?;
+
+    let positions = positions
+        .iter()
+        .map(bigint_to_u64)
+        .collect::<Result<Vec<u64>>>()?;
+
+    let witnesses = compute_witnesses(&leaves, &positions).map_err(to_napi_err)?;
+
+    Ok(witnesses
+        .into_iter()
+        .map(|witness| NativeWitness { witness })
+        .collect())
+}
+
+/// A witness held on the Rust side, built from a packed auth path so that
+/// spends don't need to call back into JS for every node.
+#[napi]
+pub struct NativeWitness {
+    pub(crate) witness: Witness,
+}
+
+#[napi]
+impl NativeWitness {
+    /// Build a witness from its root hash, tree size, and an auth path packed
+    /// as the 32 byte sibling hash of each node followed by a bitfield with
+    /// one bit per node, set when the node is on the left.
+    #[napi(factory)]
+    pub fn from_compressed(root: JsBuffer, tree_size: BigInt, packed: JsBuffer) -> Result<Self> {
+        let root_bytes = root.into_value()?;
+        let root_hash = MerkleNoteHash::read(root_bytes.as_ref())
+            .map_err(to_napi_err)?
+            .0;
+
+        let tree_size: usize = bigint_to_u64(&tree_size)?
+            .try_into()
+            .map_err(|_| to_napi_err("Value out of range"))?;
+
+        let packed_bytes = packed.into_value()?;
+        let witness = Witness::from_compressed(root_hash, tree_size, packed_bytes.as_ref())
+            .map_err(to_napi_err)?;
+
+        Ok(NativeWitness { witness })
+    }
+
+    /// The packed auth path, in the format read by `fromCompressed`.
+    #[napi]
+    pub fn to_compressed(&self) -> Buffer {
+        Buffer::from(self.witness.to_compressed())
+    }
+}
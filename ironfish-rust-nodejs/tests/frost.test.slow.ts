@@ -2,7 +2,18 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-import { ParticipantIdentity, ParticipantSecret } from "..";
+import {
+  ParticipantIdentity,
+  ParticipantSecret,
+  deserializeCommitments,
+  deserializeSignatureShares,
+  generateKey,
+  publicKeyPackageIdentifiers,
+  roundOne,
+  serializeCommitments,
+  serializeSignatureShares,
+  splitSecret,
+} from "..";
 
 describe('ParticipantIdentity', () => {
   describe('ser/de', () => {
@@ -19,3 +30,85 @@ describe('ParticipantIdentity', () => {
     })
   })
 })
+
+describe('serializeCommitments', () => {
+  it('round trips a commitments map', () => {
+    const identifiers = [1, 2].map(() =>
+      ParticipantSecret.random().toIdentity().toFrostIdentifier(),
+    )
+    const trustedDealerPackage = splitSecret(generateKey().spendingKey, 2, 2, identifiers)
+    const [{ identifier, keyPackage }] = trustedDealerPackage.keyPackages
+    const commitments = { [identifier]: roundOne(keyPackage, 0) }
+
+    const serialized = serializeCommitments(commitments)
+
+    expect(serialized.length).toEqual(4 + 96)
+    expect(deserializeCommitments(serialized)).toEqual(commitments)
+
+    // the hiding commitment is not a valid group element
+    serialized.fill(0xff, 4 + 32, 4 + 64)
+    expect(() => deserializeCommitments(serialized)).toThrow()
+  })
+
+  it('rejects invalid commitments', () => {
+    const identifiers = [1, 2].map(() =>
+      ParticipantSecret.random().toIdentity().toFrostIdentifier(),
+    )
+    const trustedDealerPackage = splitSecret(generateKey().spendingKey, 2, 2, identifiers)
+    const [{ identifier, keyPackage }] = trustedDealerPackage.keyPackages
+    const { binding } = roundOne(keyPackage, 0)
+
+    expect(() =>
+      serializeCommitments({ [identifier]: { hiding: 'ff'.repeat(32), binding } }),
+    ).toThrow()
+  })
+
+  it('rejects duplicate identifiers', () => {
+    const identifiers = [1, 2].map(() =>
+      ParticipantSecret.random().toIdentity().toFrostIdentifier(),
+    )
+    const trustedDealerPackage = splitSecret(generateKey().spendingKey, 2, 2, identifiers)
+    const [{ identifier, keyPackage }] = trustedDealerPackage.keyPackages
+    const commitment = roundOne(keyPackage, 0)
+
+    // differently cased hex decodes to the same identifier
+    expect(() =>
+      serializeCommitments({
+        [identifier.toLowerCase()]: commitment,
+        [identifier.toUpperCase()]: commitment,
+      }),
+    ).toThrow('Duplicate signing commitment identifier')
+  })
+})
+
+describe('publicKeyPackageIdentifiers', () => {
+  it('lists the identifiers of every participant', () => {
+    const identifiers = [1, 2, 3].map(() =>
+      ParticipantSecret.random().toIdentity().toFrostIdentifier(),
+    )
+
+    const trustedDealerPackage = splitSecret(generateKey().spendingKey, 2, 3, identifiers)
+
+    expect(publicKeyPackageIdentifiers(trustedDealerPackage.publicKeyPackage)).toEqual(
+      [...identifiers].sort(),
+    )
+  })
+})
+
+describe('serializeSignatureShares', () => {
+  it('round trips a signature shares map', () => {
+    const identifier = ParticipantSecret.random().toIdentity().toFrostIdentifier()
+    const shares = { [identifier]: '01' + '00'.repeat(31) }
+
+    const serialized = serializeSignatureShares(shares)
+
+    expect(serialized.length).toEqual(4 + 64)
+    expect(deserializeSignatureShares(serialized)).toEqual(shares)
+  })
+
+  it('rejects invalid shares', () => {
+    const identifier = ParticipantSecret.random().toIdentity().toFrostIdentifier()
+
+    expect(() => serializeSignatureShares({ [identifier]: 'ff'.repeat(32) })).toThrow()
+  })
+})
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-import { Asset, Transaction, generateKey } from ".."
+import { Asset, Note, Transaction, TransactionPosted, assetEffectDiff, generateKey, isCanonical, isValidTransactionEncoding, mintOwners, witnessesForPositions } from ".."
 
 describe('Transaction', () => {
   describe('post', () => {
@@ -25,5 +25,302 @@ describe('Transaction', () => {
       expect(() => { proposedTx.post(key.spendingKey, null, 0n)}).not.toThrow()
 
     })
+
+    it('throws an error when the posted transaction exceeds the maximum size', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      expect(() => { proposedTx.postWithMaxSize(key.spendingKey, null, 0n, 100)}).toThrow('exceeds the maximum of 100 bytes')
+    })
+  })
+
+  describe('recipients', () => {
+    it('returns the outputs sent by the outgoing view key owner', () => {
+      const key = generateKey()
+      const other = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+
+      expect(posted.recipients(key.outgoingViewKey)).toEqual([
+        { address: key.publicAddress, value: 5n, assetId: asset.id() },
+      ])
+      expect(posted.recipients(other.outgoingViewKey)).toEqual([])
+    })
+
+    it('counts distinct recipients', () => {
+      const key = generateKey()
+      const other = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+
+      expect(posted.distinctRecipients(key.outgoingViewKey)).toEqual(1)
+      expect(posted.distinctRecipients(other.outgoingViewKey)).toEqual(0)
+    })
+
+    it('checks whether a note is one of the outputs', () => {
+      const key = generateKey()
+      const note = new Note(key.publicAddress, 5n, '', Asset.nativeId(), key.publicAddress)
+      const other = new Note(key.publicAddress, 5n, '', Asset.nativeId(), key.publicAddress)
+      const proposedTx = new Transaction(1)
+      proposedTx.output(note)
+
+      const posted = new TransactionPosted(proposedTx.post_miners_fee(key.spendingKey))
+
+      expect(posted.containsOutput(note)).toBe(true)
+      expect(posted.containsOutput(other)).toBe(false)
+    })
+
+    it('checks for mints and burns of an asset', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const other = new Asset(key.publicAddress, 'othercoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+      proposedTx.burn(asset.id(), 2n)
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+
+      expect(posted.hasMintFor(asset.id())).toBe(true)
+      expect(posted.hasBurnFor(asset.id())).toBe(true)
+      expect(posted.hasMintFor(other.id())).toBe(false)
+      expect(posted.hasBurnFor(other.id())).toBe(false)
+    })
+
+    it('returns the serialized asset of a mint', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+
+      expect(posted.mintAssetBytes(0)).toEqual(asset.serialize())
+      expect(() => posted.mintAssetBytes(1)).toThrow('Value out of range')
+    })
+
+    it('returns the public inputs of each proof', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+      const inputs = posted.outputPublicInputs(0)
+
+      expect(inputs).toHaveLength(7)
+      expect(inputs[6]).toEqual(posted.summary().outputCommitments[0])
+      expect(() => posted.outputPublicInputs(1)).toThrow('Value out of range')
+      expect(() => posted.spendPublicInputs(0)).toThrow('Value out of range')
+    })
+
+    it('lists the assets visible to an incoming view key', () => {
+      const key = generateKey()
+      const other = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+
+      expect(posted.visibleAssets(key.incomingViewKey)).toEqual([asset.id()])
+      expect(posted.visibleAssets(other.incomingViewKey)).toEqual([])
+    })
+
+    it('detects outputs sent to the same address', () => {
+      const key = generateKey()
+      const other = generateKey()
+      const proposedTx = new Transaction(1)
+      proposedTx.output(new Note(other.publicAddress, 0n, '', Asset.nativeId(), key.publicAddress))
+      proposedTx.output(new Note(other.publicAddress, 0n, '', Asset.nativeId(), key.publicAddress))
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+
+      expect(posted.hasAddressReuse(key.outgoingViewKey)).toBe(true)
+      expect(posted.hasAddressReuse(other.outgoingViewKey)).toBe(false)
+    })
+
+    it('detects dust outputs', () => {
+      const key = generateKey()
+      const other = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+
+      expect(posted.hasDustOutputs(6n, key.outgoingViewKey)).toBe(true)
+      expect(posted.hasDustOutputs(5n, key.outgoingViewKey)).toBe(false)
+      expect(posted.hasDustOutputs(6n, other.outgoingViewKey)).toBe(false)
+      expect(() => posted.hasDustOutputs(-1n, key.outgoingViewKey)).toThrow('Value out of range')
+    })
+
+    it('verifies claimed output values', () => {
+      const key = generateKey()
+      const other = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      const posted = new TransactionPosted(proposedTx.post(key.spendingKey, null, 0n))
+
+      expect(posted.verifyOutputClaim(0, 5n, key.incomingViewKey)).toBe(true)
+      expect(posted.verifyOutputClaim(0, 6n, key.incomingViewKey)).toBe(false)
+      expect(posted.verifyOutputClaim(0, 5n, other.incomingViewKey)).toBe(false)
+    })
+  })
+
+  describe('outputSerialized', () => {
+    it('adds an output from a serialized note', () => {
+      const key = generateKey()
+      const note = new Note(key.publicAddress, 5n, '', Asset.nativeId(), key.publicAddress)
+      const proposedTx = new Transaction(1)
+      proposedTx.outputSerialized(note.serialize())
+
+      const posted = new TransactionPosted(proposedTx.post_miners_fee(key.spendingKey))
+
+      expect(posted.containsOutput(note)).toBe(true)
+    })
+  })
+
+  describe('witnessesForPositions', () => {
+    it('spends notes with witnesses computed from a tree snapshot', () => {
+      const key = generateKey()
+      const note = new Note(key.publicAddress, 5n, '', Asset.nativeId(), key.publicAddress)
+      const other = new Note(key.publicAddress, 3n, '', Asset.nativeId(), key.publicAddress)
+      const tree = Buffer.concat([other.commitment(), note.commitment()])
+
+      const [witness] = witnessesForPositions(tree, [1n])
+      const proposedTx = new Transaction(1)
+      proposedTx.spendCompressed(note, witness)
+      proposedTx.output(new Note(key.publicAddress, 5n, '', Asset.nativeId(), key.publicAddress))
+
+      expect(() => { proposedTx.post(key.spendingKey, null, 0n) }).not.toThrow()
+      expect(() => witnessesForPositions(tree, [2n])).toThrow()
+      expect(() => witnessesForPositions(tree.subarray(1), [0n])).toThrow('Invalid note commitment tree length')
+    })
+  })
+
+  describe('checkBalanced', () => {
+    it('rejects fees that are not a valid u64', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+
+      expect(proposedTx.checkBalanced(0n)).toEqual({})
+      expect(() => proposedTx.checkBalanced(-1n)).toThrow('Value out of range')
+      expect(() => proposedTx.checkBalanced(2n ** 64n)).toThrow('Value out of range')
+    })
+  })
+
+  describe('setAllowedAssets', () => {
+    it('rejects assets that are not in the allowlist', () => {
+      const key = generateKey()
+      const allowed = new Asset(key.publicAddress, 'allowed', '')
+      const other = new Asset(key.publicAddress, 'other', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.setAllowedAssets([allowed.id()])
+
+      expect(() => { proposedTx.mint(allowed, 5n) }).not.toThrow()
+      expect(() => { proposedTx.mint(other, 5n) }).toThrow('is not allowed')
+      expect(() => { proposedTx.burn(other.id(), 5n) }).toThrow('is not allowed')
+    })
+  })
+
+  describe('isValidTransactionEncoding', () => {
+    it('accepts serialized transactions and rejects other buffers', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+      const serialized = proposedTx.post(key.spendingKey, null, 0n)
+
+      expect(isValidTransactionEncoding(serialized)).toBe(true)
+      expect(isValidTransactionEncoding(serialized.subarray(1))).toBe(false)
+      expect(isValidTransactionEncoding(Buffer.concat([serialized, Buffer.from([0])]))).toBe(false)
+    })
+
+    it('rejects oversized description counts', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+      const serialized = proposedTx.post(key.spendingKey, null, 0n)
+
+      // The spend count follows the version byte
+      serialized.writeBigUInt64LE(2n ** 64n - 1n, 1)
+      expect(isValidTransactionEncoding(serialized)).toBe(false)
+    })
+  })
+
+  describe('assetEffectDiff', () => {
+    it('returns the change in mints and burns per asset', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const replaced = new Transaction(1)
+      replaced.mint(asset, 5n)
+      const replacing = new Transaction(1)
+      replacing.mint(asset, 8n)
+      replacing.burn(asset.id(), 2n)
+
+      const a = replaced.post(key.spendingKey, null, 0n)
+      const b = replacing.post(key.spendingKey, null, 0n)
+
+      expect(assetEffectDiff(a, b)).toEqual({
+        [asset.id().toString('hex')]: { minted: 3n, burned: 2n, net: 1n },
+      })
+      expect(assetEffectDiff(a, a)).toEqual({})
+    })
+  })
+
+  describe('mintOwners', () => {
+    it('returns each mint owner once across transactions', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const first = new Transaction(1)
+      first.mint(asset, 5n)
+      const second = new Transaction(1)
+      second.mint(asset, 3n)
+
+      const serialized = [
+        first.post(key.spendingKey, null, 0n),
+        second.post(key.spendingKey, null, 0n),
+      ]
+
+      expect(mintOwners(serialized)).toEqual([key.publicAddress])
+    })
+  })
+
+  describe('isCanonical', () => {
+    it('rejects trailing bytes', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+      const serialized = proposedTx.post(key.spendingKey, null, 0n)
+
+      expect(isCanonical(serialized)).toBe(true)
+      expect(isCanonical(Buffer.concat([serialized, Buffer.from([0])]))).toBe(false)
+    })
+
+    it('rejects oversized description counts', () => {
+      const key = generateKey()
+      const asset = new Asset(key.publicAddress, 'testcoin', '')
+      const proposedTx = new Transaction(1)
+      proposedTx.mint(asset, 5n)
+      const serialized = proposedTx.post(key.spendingKey, null, 0n)
+
+      // The mint count follows the version byte and the spend and output counts
+      serialized.writeBigUInt64LE(2n ** 64n - 1n, 17)
+      expect(isCanonical(serialized)).toBe(false)
+    })
   })
 })
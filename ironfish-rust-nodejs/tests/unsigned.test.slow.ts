@@ -3,7 +3,7 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 import { UnsignedTransaction } from ".."
-import { Asset, Transaction, generateKey } from ".."
+import { Asset, Transaction, generateKey, transactionKind } from ".."
 
 describe('UnsignedTransaction', () => {
     describe('ser/de', () => {
@@ -25,4 +25,116 @@ describe('UnsignedTransaction', () => {
 
         })
     })
+
+    describe('buildUnsigned', () => {
+        it('returns an unsigned tx without serializing it', () => {
+            const key = generateKey()
+            const asset = new Asset(key.publicAddress, 'testcoin', '')
+            const proposedTx = new Transaction(2)
+            proposedTx.mint(asset, 5n)
+            const unsignedTx = proposedTx.buildUnsigned(
+                key.proofGenerationKey,
+                key.viewKey,
+                key.outgoingViewKey,
+                key.publicAddress,
+                0n,
+            )
+
+            const deserialized = new UnsignedTransaction(unsignedTx.serialize())
+            expect(deserialized.serialize()).toEqual(unsignedTx.serialize())
+        })
+    })
+
+    describe('exportSession', () => {
+        it('round trips the transaction through importSession', () => {
+            const key = generateKey()
+            const asset = new Asset(key.publicAddress, 'testcoin', '')
+            const proposedTx = new Transaction(2)
+            proposedTx.mint(asset, 5n)
+            const unsignedTx = proposedTx.buildUnsigned(
+                key.proofGenerationKey,
+                key.viewKey,
+                key.outgoingViewKey,
+                key.publicAddress,
+                0n,
+            )
+
+            const session = UnsignedTransaction.importSession(unsignedTx.exportSession({}, {}))
+            expect(session.transaction).toEqual(unsignedTx.serialize())
+            expect(session.commitments).toEqual({})
+            expect(session.signatureShares).toEqual({})
+        })
+    })
+
+    describe('toDisplayJson', () => {
+        it('describes the transaction deterministically', () => {
+            const key = generateKey()
+            const asset = new Asset(key.publicAddress, 'testcoin', '')
+            const proposedTx = new Transaction(2)
+            proposedTx.mint(asset, 5n)
+            proposedTx.burn(asset.id(), 2n)
+            const unsignedTx = new UnsignedTransaction(proposedTx.build(
+                key.proofGenerationKey,
+                key.viewKey,
+                key.outgoingViewKey,
+                key.publicAddress,
+                0n,
+            ))
+
+            const json = unsignedTx.toDisplayJson(key.outgoingViewKey)
+            const display = JSON.parse(json)
+            expect(display.spends).toEqual([])
+            expect(display.mints).toEqual([
+                { assetId: asset.id().toString('hex'), value: '5', owner: key.publicAddress },
+            ])
+            expect(display.burns).toEqual([{ assetId: asset.id().toString('hex'), value: '2' }])
+            expect(display.outputs).toHaveLength(1)
+            expect(display.outputs[0].value).toEqual('3')
+            expect(display.fee).toEqual('0')
+            expect(new UnsignedTransaction(unsignedTx.serialize()).toDisplayJson(key.outgoingViewKey)).toEqual(json)
+
+            expect(Object.keys(JSON.parse(unsignedTx.toDisplayJson()).outputs[0])).toEqual(['commitment'])
+        })
+    })
+
+    describe('transactionKind', () => {
+        it('classifies unsigned and posted transactions', () => {
+            const key = generateKey()
+            const asset = new Asset(key.publicAddress, 'testcoin', '')
+            const proposedTx = new Transaction(2)
+            proposedTx.mint(asset, 5n)
+            const unsignedTxBuffer = proposedTx.build(
+                key.proofGenerationKey,
+                key.viewKey,
+                key.outgoingViewKey,
+                key.publicAddress,
+                0n,
+            )
+            const postedTx = new Transaction(2)
+            postedTx.mint(asset, 5n)
+            const postedTxBuffer = postedTx.post(key.spendingKey, null, 0n)
+
+            expect(transactionKind(unsignedTxBuffer)).toEqual('unsigned')
+            expect(transactionKind(postedTxBuffer)).toEqual('posted')
+            expect(() => transactionKind(Buffer.from([0]))).toThrow('not a serialized transaction')
+        })
+
+        it('rejects oversized description counts', () => {
+            const key = generateKey()
+            const asset = new Asset(key.publicAddress, 'testcoin', '')
+            const proposedTx = new Transaction(2)
+            proposedTx.mint(asset, 5n)
+            const unsignedTxBuffer = proposedTx.build(
+                key.proofGenerationKey,
+                key.viewKey,
+                key.outgoingViewKey,
+                key.publicAddress,
+                0n,
+            )
+
+            // The spend count follows the version byte
+            unsignedTxBuffer.writeBigUInt64LE(2n ** 64n - 1n, 1)
+            expect(() => transactionKind(unsignedTxBuffer)).toThrow('not a serialized transaction')
+        })
+    })
 })
@@ -46,6 +46,18 @@ impl Asset {
         if trimmed_name.is_empty() {
             return Err(IronfishError::new(IronfishErrorKind::InvalidData));
         }
+        // The name is stored as UTF-8 bytes, so multi-byte characters count
+        // more than once against the limit
+        if trimmed_name.len() > NAME_LENGTH {
+            return Err(IronfishError::new_with_source(
+                IronfishErrorKind::IllegalValue,
+                format!(
+                    "Asset name is {} bytes, exceeding the maximum of {} bytes",
+                    trimmed_name.len(),
+                    NAME_LENGTH
+                ),
+            ));
+        }
 
         let name_bytes = str_to_array(trimmed_name);
         let metadata_bytes = str_to_array(metadata);
@@ -61,6 +73,39 @@ impl Asset {
         }
     }
 
+    /// Reconstruct an asset from its creator, name, and metadata, checking
+    /// that the derived identifier matches the one it was stored with.
+    pub fn from_parts(
+        creator: PublicAddress,
+        name: &str,
+        metadata: &str,
+        expected_id: &AssetIdentifier,
+    ) -> Result<Asset, IronfishError> {
+        let asset = Asset::new(creator, name, metadata)?;
+
+        if asset.id() != expected_id {
+            return Err(IronfishError::new(
+                IronfishErrorKind::InvalidAssetIdentifier,
+            ));
+        }
+
+        Ok(asset)
+    }
+
+    /// Check that the identifier of this asset is the one derived from its
+    /// creator, name, metadata, and nonce.
+    pub fn verify(&self) -> Result<(), IronfishError> {
+        let derived = Asset::new_with_nonce(self.creator, self.name, self.metadata, self.nonce)?;
+
+        if derived.id() != self.id() {
+            return Err(IronfishError::new(
+                IronfishErrorKind::InvalidAssetIdentifier,
+            ));
+        }
+
+        Ok(())
+    }
+
     pub fn new_with_nonce(
         creator: PublicAddress,
         name: [u8; NAME_LENGTH],
@@ -151,7 +196,20 @@ mod test {
 
     use crate::{util::str_to_array, PublicAddress, SaplingKey};
 
-    use super::{Asset, ASSET_LENGTH};
+    use super::{Asset, ASSET_LENGTH, NAME_LENGTH};
+
+    #[test]
+    fn test_asset_verify() {
+        let key = SaplingKey::generate_key();
+        let mut asset =
+            Asset::new(key.public_address(), "name", "metadata").expect("can create an asset");
+        assert!(asset.verify().is_ok());
+
+        let other =
+            Asset::new(key.public_address(), "other", "metadata").expect("can create an asset");
+        asset.id = other.id;
+        assert!(asset.verify().is_err());
+    }
 
     #[test]
     fn test_asset_new() {
@@ -167,6 +225,23 @@ mod test {
         assert_eq!(asset.metadata, str_to_array(metadata));
     }
 
+    #[test]
+    fn test_asset_from_parts() {
+        let key = SaplingKey::generate_key();
+        let creator = key.public_address();
+        let name = "name";
+        let metadata = "{ 'token_identifier': '0x123' }";
+
+        let asset = Asset::new(creator, name, metadata).expect("can create an asset");
+
+        let rebuilt = Asset::from_parts(creator, name, metadata, asset.id())
+            .expect("can rebuild an asset from its parts");
+        assert_eq!(asset, rebuilt);
+
+        let other = Asset::new(creator, "other", metadata).expect("can create an asset");
+        assert!(Asset::from_parts(creator, name, metadata, other.id()).is_err());
+    }
+
     #[test]
     fn test_asset_name_must_be_set() {
         let key = SaplingKey::generate_key();
@@ -183,6 +258,20 @@ mod test {
         assert!(good_asset.is_ok());
     }
 
+    #[test]
+    fn test_asset_name_length_in_bytes() {
+        let key = SaplingKey::generate_key();
+        let creator = key.public_address();
+        let metadata = "";
+
+        assert!(Asset::new(creator, &"a".repeat(NAME_LENGTH), metadata).is_ok());
+        assert!(Asset::new(creator, &"a".repeat(NAME_LENGTH + 1), metadata).is_err());
+
+        // 11 three byte characters fit in 32 characters, but not 32 bytes
+        assert!(Asset::new(creator, &"\u{20ac}".repeat(10), metadata).is_ok());
+        assert!(Asset::new(creator, &"\u{20ac}".repeat(11), metadata).is_err());
+    }
+
     #[test]
     fn test_asset_new_with_nonce() {
         let public_address = [
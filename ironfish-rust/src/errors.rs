@@ -31,6 +31,7 @@ pub enum IronfishErrorKind {
     FailedSignatureVerification,
     IllegalValue,
     InconsistentWitness,
+    InsufficientSignatureShares,
     InvalidAssetIdentifier,
     InvalidAuthorizingKey,
     InvalidBalance,
@@ -243,7 +243,7 @@ impl SaplingKey {
     ///
     /// This method is only called once, but it's kind of messy, so I pulled it
     /// out of the constructor for easier maintenance.
-    fn hash_viewing_key(
+    pub(crate) fn hash_viewing_key(
         authorizing_key: &SubgroupPoint,
         nullifier_deriving_key: &SubgroupPoint,
     ) -> Result<jubjub::Fr, IronfishError> {
@@ -12,14 +12,16 @@
 //! that you have spent.
 //!
 
-use super::PublicAddress;
+use super::{PublicAddress, SaplingKey};
 use crate::{
     errors::{IronfishError, IronfishErrorKind},
+    keys::proof_generation_key::ProofGenerationKey,
     serializing::{bytes_to_hex, hex_to_bytes, read_scalar},
 };
 use bip39::{Language, Mnemonic};
 use blake2b_simd::Params as Blake2b;
 use group::GroupEncoding;
+use ironfish_zkp::constants::PROOF_GENERATION_KEY_GENERATOR;
 use jubjub::SubgroupPoint;
 
 use std::io;
@@ -136,6 +138,25 @@ impl ViewKey {
         result[32..].copy_from_slice(&self.nullifier_deriving_key.to_bytes());
         result
     }
+
+    /// Check that the given proof generation key belongs to the same account
+    /// as this view key, i.e. that it has the same authorizing key and derives
+    /// the same nullifier deriving key.
+    pub fn matches_proof_generation_key(&self, proof_generation_key: &ProofGenerationKey) -> bool {
+        self.authorizing_key == proof_generation_key.ak
+            && self.nullifier_deriving_key
+                == *PROOF_GENERATION_KEY_GENERATOR * proof_generation_key.nsk
+    }
+
+    /// Derive the incoming view key of the account this view key belongs to.
+    pub fn incoming_view_key(&self) -> Result<IncomingViewKey, IronfishError> {
+        Ok(IncomingViewKey {
+            view_key: SaplingKey::hash_viewing_key(
+                &self.authorizing_key,
+                &self.nullifier_deriving_key,
+            )?,
+        })
+    }
 }
 
 /// Key that allows someone to view a transaction that you have spent.
@@ -229,6 +250,30 @@ pub(crate) fn shared_secret(
 mod test {
     use crate::{SaplingKey, ViewKey};
 
+    #[test]
+    fn test_matches_proof_generation_key() {
+        let key = SaplingKey::generate_key();
+        let other_key = SaplingKey::generate_key();
+
+        assert!(key
+            .view_key()
+            .matches_proof_generation_key(&key.sapling_proof_generation_key()));
+        assert!(!key
+            .view_key()
+            .matches_proof_generation_key(&other_key.sapling_proof_generation_key()));
+    }
+
+    #[test]
+    fn test_incoming_view_key() {
+        let key = SaplingKey::generate_key();
+
+        let incoming_view_key = key.view_key().incoming_view_key().unwrap();
+        assert_eq!(
+            incoming_view_key.hex_key(),
+            key.incoming_view_key().hex_key()
+        );
+    }
+
     #[test]
     fn test_view_key() {
         let key = SaplingKey::from_hex(
@@ -23,6 +23,7 @@ pub mod witness;
 pub use {
     ironfish_frost::frost,
     ironfish_frost::participant,
+    ironfish_zkp::redjubjub,
     keys::{IncomingViewKey, OutgoingViewKey, PublicAddress, SaplingKey, ViewKey},
     merkle_note::MerkleNote,
     merkle_note_hash::MerkleNoteHash,
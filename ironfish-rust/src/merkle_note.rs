@@ -172,6 +172,12 @@ impl MerkleNote {
         Ok(())
     }
 
+    /// The note encryption keys, encrypted with the sender's outgoing view
+    /// key. Referred to as the outgoing cipher text in the literature.
+    pub fn note_encryption_keys(&self) -> &[u8; NOTE_ENCRYPTION_KEY_SIZE] {
+        &self.note_encryption_keys
+    }
+
     pub fn merkle_hash(&self) -> MerkleNoteHash {
         MerkleNoteHash::new(self.note_commitment)
     }
@@ -187,6 +193,20 @@ impl MerkleNote {
         Ok(note)
     }
 
+    /// Decrypt only the value of this note using the owner's incoming view key.
+    ///
+    /// Unlike [`MerkleNote::decrypt_note_for_owner`], the note is not rebuilt
+    /// and checked against the note commitment, so the value is authenticated
+    /// only by the encryption MAC. Use this for balance scans, and decrypt the
+    /// full note before spending it.
+    pub fn decrypt_value_for_owner(
+        &self,
+        owner_view_key: &IncomingViewKey,
+    ) -> Result<u64, IronfishError> {
+        let shared_secret = owner_view_key.shared_secret(&self.ephemeral_public_key);
+        Note::decrypt_value(&shared_secret, &self.encrypted_note)
+    }
+
     pub fn decrypt_note_for_spender(
         &self,
         spender_key: &OutgoingViewKey,
@@ -229,7 +249,7 @@ pub(crate) fn sapling_auth_path(witness: &dyn WitnessTrait) -> Vec<Option<(Scala
 /// on an assumption that the tree is complete and binary. And I didn't feel
 /// like making Witness a trait since it's otherwise very simple.
 /// So this hacky function gets to live here.
-pub(crate) fn position(witness: &dyn WitnessTrait) -> u64 {
+pub fn position(witness: &dyn WitnessTrait) -> u64 {
     let mut pos = 0;
     for (i, element) in witness.get_auth_path().iter().enumerate() {
         if let WitnessNode::Right(_) = element {
@@ -371,6 +391,16 @@ mod test {
         assert!(merkle_note
             .decrypt_note_for_spender(receiver_key.outgoing_view_key())
             .is_err());
+
+        assert_eq!(
+            merkle_note
+                .decrypt_value_for_owner(receiver_key.incoming_view_key())
+                .expect("should be able to decrypt value for owner"),
+            42
+        );
+        assert!(merkle_note
+            .decrypt_value_for_owner(spender_key.incoming_view_key())
+            .is_err());
     }
 
     #[test]
@@ -3,10 +3,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 use crate::{
-    assets::asset_identifier::AssetIdentifier,
+    assets::asset_identifier::{AssetIdentifier, NATIVE_ASSET},
     errors::{IronfishError, IronfishErrorKind},
     keys::PUBLIC_ADDRESS_SIZE,
-    util::str_to_array,
+    util::{str_to_array, volatile_overwrite},
     ViewKey,
 };
 
@@ -18,14 +18,14 @@ use blake2s_simd::Params as Blake2sParams;
 use blstrs::Scalar;
 use byteorder::{ByteOrder, LittleEndian, ReadBytesExt, WriteBytesExt};
 use ff::{Field, PrimeField};
-use group::{Curve, GroupEncoding};
+use group::{Curve, Group, GroupEncoding};
 use ironfish_zkp::{
     constants::{ASSET_ID_LENGTH, NULLIFIER_POSITION_GENERATOR, PRF_NF_PERSONALIZATION},
     util::commitment_full_point,
     Nullifier,
 };
 use jubjub::SubgroupPoint;
-use rand::thread_rng;
+use rand::{thread_rng, RngCore};
 use std::{fmt, io, io::Read};
 pub const ENCRYPTED_NOTE_SIZE: usize =
     SCALAR_SIZE + MEMO_SIZE + AMOUNT_VALUE_SIZE + ASSET_ID_LENGTH + PUBLIC_ADDRESS_SIZE;
@@ -44,6 +44,17 @@ pub const AMOUNT_VALUE_SIZE: usize = 8;
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
 pub struct Memo(pub [u8; MEMO_SIZE]);
 
+impl Memo {
+    /// The number of bytes of the memo in use, ignoring the trailing zero
+    /// bytes that pad it to [`MEMO_SIZE`].
+    pub fn used_bytes(&self) -> usize {
+        self.0
+            .iter()
+            .rposition(|byte| *byte != 0)
+            .map_or(0, |index| index + 1)
+    }
+}
+
 impl From<&str> for Memo {
     fn from(string: &str) -> Self {
         let memo_bytes = str_to_array(string);
@@ -108,7 +119,21 @@ impl<'a> Note {
         asset_id: AssetIdentifier,
         sender: PublicAddress,
     ) -> Self {
-        let randomness: jubjub::Fr = jubjub::Fr::random(thread_rng());
+        Self::new_with_rng(owner, value, memo, asset_id, sender, thread_rng())
+    }
+
+    /// Construct a new Note, drawing its randomness from the given rng.
+    /// Passing a seeded rng makes the note (and its commitment) deterministic,
+    /// which is only appropriate in tests.
+    pub fn new_with_rng<R: RngCore>(
+        owner: PublicAddress,
+        value: u64,
+        memo: impl Into<Memo>,
+        asset_id: AssetIdentifier,
+        sender: PublicAddress,
+        rng: R,
+    ) -> Self {
+        let randomness: jubjub::Fr = jubjub::Fr::random(rng);
 
         Self {
             owner,
@@ -224,6 +249,18 @@ impl<'a> Note {
         self.value
     }
 
+    /// Overwrite the secret values held by this note, including who it is
+    /// for, who sent it, and which asset it holds. The note no longer matches
+    /// its commitment after this is called.
+    pub(crate) fn zeroize(&mut self) {
+        volatile_overwrite(&mut self.owner, PublicAddress(SubgroupPoint::identity()));
+        volatile_overwrite(&mut self.value, 0);
+        volatile_overwrite(&mut self.randomness, jubjub::Fr::zero());
+        volatile_overwrite(&mut self.memo, Memo::default());
+        volatile_overwrite(&mut self.asset_id, NATIVE_ASSET);
+        volatile_overwrite(&mut self.sender, PublicAddress(SubgroupPoint::identity()));
+    }
+
     pub fn memo(&self) -> Memo {
         self.memo
     }
@@ -337,6 +374,20 @@ impl<'a> Note {
         }
     }
 
+    /// Decrypt only the value of an encrypted note, without reading the
+    /// remaining fields.
+    pub(crate) fn decrypt_value(
+        shared_secret: &[u8; 32],
+        encrypted_bytes: &[u8; ENCRYPTED_NOTE_SIZE + aead::MAC_SIZE],
+    ) -> Result<u64, IronfishError> {
+        let plaintext_bytes: [u8; ENCRYPTED_NOTE_SIZE] =
+            aead::decrypt(shared_secret, encrypted_bytes)?;
+
+        Ok(LittleEndian::read_u64(
+            &plaintext_bytes[SCALAR_SIZE..SCALAR_SIZE + AMOUNT_VALUE_SIZE],
+        ))
+    }
+
     fn decrypt_note_parts(
         shared_secret: &[u8; 32],
         encrypted_bytes: &[u8; ENCRYPTED_NOTE_SIZE + aead::MAC_SIZE],
@@ -366,6 +417,9 @@ mod test {
         assets::asset_identifier::NATIVE_ASSET,
         keys::{shared_secret, EphemeralKeyPair, SaplingKey},
     };
+    use ff::Field;
+    use group::Group;
+    use jubjub::SubgroupPoint;
 
     #[test]
     fn test_plaintext_serialization() {
@@ -459,4 +513,96 @@ mod test {
         let memo = Memo::from(string);
         assert_eq!(&memo.0[..6], b"a memo");
     }
+
+    #[test]
+    fn test_new_with_rng_is_deterministic() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let address = SaplingKey::generate_key().public_address();
+        let note = Note::new_with_rng(
+            address,
+            42,
+            "",
+            NATIVE_ASSET,
+            address,
+            StdRng::seed_from_u64(7),
+        );
+        let note2 = Note::new_with_rng(
+            address,
+            42,
+            "",
+            NATIVE_ASSET,
+            address,
+            StdRng::seed_from_u64(7),
+        );
+        assert_eq!(note.randomness, note2.randomness);
+        assert_eq!(note.commitment(), note2.commitment());
+    }
+
+    #[test]
+    fn test_commitment_is_stable() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let owner_key: SaplingKey = SaplingKey::generate_key();
+        let sender_key: SaplingKey = SaplingKey::generate_key();
+        let note = Note::new_with_rng(
+            owner_key.public_address(),
+            42,
+            "a memo",
+            NATIVE_ASSET,
+            sender_key.public_address(),
+            StdRng::seed_from_u64(7),
+        );
+
+        let mut serialized = Vec::new();
+        note.write(&mut serialized)
+            .expect("Should serialize cleanly");
+        let deserialized = Note::read(&serialized[..]).expect("It should deserialize cleanly");
+        assert_eq!(deserialized.commitment(), note.commitment());
+
+        // Rebuilding the note from its fields, with the same randomness, must
+        // give the same commitment
+        let reconstructed = Note::new_with_rng(
+            note.owner(),
+            note.value(),
+            note.memo(),
+            *note.asset_id(),
+            note.sender(),
+            StdRng::seed_from_u64(7),
+        );
+        assert_eq!(reconstructed.randomness, note.randomness);
+        assert_eq!(reconstructed.commitment(), note.commitment());
+
+        // and changing any of them must not
+        let different_value = Note::new_with_rng(
+            note.owner(),
+            note.value() + 1,
+            note.memo(),
+            *note.asset_id(),
+            note.sender(),
+            StdRng::seed_from_u64(7),
+        );
+        assert_ne!(different_value.commitment(), note.commitment());
+    }
+
+    #[test]
+    fn test_zeroize() {
+        let owner_key: SaplingKey = SaplingKey::generate_key();
+        let mut note = Note::new(
+            owner_key.public_address(),
+            42,
+            "a memo",
+            NATIVE_ASSET,
+            owner_key.public_address(),
+        );
+
+        note.zeroize();
+
+        assert_eq!(note.value, 0);
+        assert_eq!(note.randomness, jubjub::Fr::zero());
+        assert_eq!(note.memo, Memo::default());
+        assert_eq!(note.owner.0, SubgroupPoint::identity());
+        assert_eq!(note.asset_id, NATIVE_ASSET);
+        assert_eq!(note.sender.0, SubgroupPoint::identity());
+    }
 }
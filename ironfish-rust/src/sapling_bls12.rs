@@ -1,10 +1,18 @@
 /* This Source Code Form is subject to the terms of the Mozilla Public
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
+#[cfg(feature = "verifying-keys-override")]
+use bellperson::groth16;
+use bellperson::groth16::PreparedVerifyingKey;
+use blstrs::Bls12;
 pub use blstrs::Scalar;
 use lazy_static::lazy_static;
 use std::sync::Arc;
+#[cfg(feature = "verifying-keys-override")]
+use std::sync::RwLock;
 
+#[cfg(feature = "verifying-keys-override")]
+use crate::errors::IronfishError;
 use crate::Sapling;
 
 // Loads the Sapling object once when dereferenced,
@@ -13,6 +21,75 @@ lazy_static! {
     pub static ref SAPLING: Arc<Sapling> = Arc::new(load());
 }
 
+#[cfg(feature = "verifying-keys-override")]
+lazy_static! {
+    static ref VERIFYING_KEYS_OVERRIDE: RwLock<Option<Arc<VerifyingKeys>>> = RwLock::new(None);
+}
+
+/// Prepared verifying keys used in place of the ones loaded with [`SAPLING`]
+/// when verifying transactions.
+pub struct VerifyingKeys {
+    pub spend: PreparedVerifyingKey<Bls12>,
+    pub output: PreparedVerifyingKey<Bls12>,
+    pub mint: PreparedVerifyingKey<Bls12>,
+}
+
+/// Verify transactions against the given serialized verifying keys instead of
+/// the built-in ones, until [`reset_verifying_keys`] is called. This allows
+/// testing against alternate circuits, such as on testnets or during circuit
+/// upgrades. Proving is not affected. Only available with the
+/// `verifying-keys-override` feature.
+#[cfg(feature = "verifying-keys-override")]
+pub fn set_verifying_keys(spend: &[u8], output: &[u8], mint: &[u8]) -> Result<(), IronfishError> {
+    let keys = VerifyingKeys {
+        spend: groth16::prepare_verifying_key(&groth16::VerifyingKey::read(spend)?),
+        output: groth16::prepare_verifying_key(&groth16::VerifyingKey::read(output)?),
+        mint: groth16::prepare_verifying_key(&groth16::VerifyingKey::read(mint)?),
+    };
+
+    *VERIFYING_KEYS_OVERRIDE.write().unwrap() = Some(Arc::new(keys));
+
+    Ok(())
+}
+
+/// Go back to verifying transactions against the built-in verifying keys.
+#[cfg(feature = "verifying-keys-override")]
+pub fn reset_verifying_keys() {
+    *VERIFYING_KEYS_OVERRIDE.write().unwrap() = None;
+}
+
+/// The verifying keys set with [`set_verifying_keys`], if any.
+#[cfg(feature = "verifying-keys-override")]
+fn verifying_keys_override() -> Option<Arc<VerifyingKeys>> {
+    VERIFYING_KEYS_OVERRIDE.read().unwrap().clone()
+}
+
+#[cfg(not(feature = "verifying-keys-override"))]
+fn verifying_keys_override() -> Option<Arc<VerifyingKeys>> {
+    None
+}
+
+/// Call `f` with the spend, output and mint verifying keys that proofs are
+/// checked against: the keys set with `set_verifying_keys` if any, otherwise
+/// the built-in ones. Every proof verifier goes through this so that single
+/// and batch verification always agree.
+pub(crate) fn with_verifying_keys<T>(
+    f: impl FnOnce(
+        &PreparedVerifyingKey<Bls12>,
+        &PreparedVerifyingKey<Bls12>,
+        &PreparedVerifyingKey<Bls12>,
+    ) -> T,
+) -> T {
+    match verifying_keys_override() {
+        Some(keys) => f(&keys.spend, &keys.output, &keys.mint),
+        None => f(
+            &SAPLING.spend_verifying_key,
+            &SAPLING.output_verifying_key,
+            &SAPLING.mint_verifying_key,
+        ),
+    }
+}
+
 /// Load a sapling object configured to a BLS12 jubjub curve. This is currently
 /// the only pairing for which a jubjub curve has been defined, and is the
 /// default implementation.
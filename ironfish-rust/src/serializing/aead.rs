@@ -13,6 +13,13 @@ pub const MAC_SIZE: usize = 16;
 ///
 /// This is just a facade around the ChaCha20Poly1305 struct. The nonce and
 /// associated data are zeroed.
+///
+/// A zero nonce is safe because every key passed here is derived for a
+/// single note, so no key is ever used twice. The nonce is also not part of
+/// the encrypted note format: recipients always decrypt with the zero nonce,
+/// so a caller-chosen nonce would produce notes nobody could decrypt. Callers
+/// that want deterministic, recoverable outputs must control the key
+/// derivation inputs instead.
 pub(crate) fn encrypt<const SIZE: usize>(
     key: &[u8; 32],
     plaintext: &[u8],
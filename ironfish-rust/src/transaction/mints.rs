@@ -149,6 +149,12 @@ impl UnsignedMintDescription {
         Ok(self.description)
     }
 
+    /// The mint description this will become once signed, with a placeholder
+    /// signature.
+    pub fn description(&self) -> &MintDescription {
+        &self.description
+    }
+
     pub fn add_signature(mut self, signature: Signature) -> MintDescription {
         self.description.authorizing_signature = signature;
         self.description
@@ -2,7 +2,7 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
-use blstrs::Bls12;
+use blstrs::{Bls12, Scalar};
 use ff::Field;
 use outputs::OutputBuilder;
 use spends::{SpendBuilder, UnsignedSpendDescription};
@@ -16,17 +16,19 @@ use crate::{
     errors::{IronfishError, IronfishErrorKind},
     keys::{PublicAddress, SaplingKey},
     note::Note,
-    sapling_bls12::SAPLING,
+    sapling_bls12::with_verifying_keys,
+    util::volatile_overwrite,
     witness::WitnessTrait,
     OutgoingViewKey, OutputDescription, SpendDescription, ViewKey,
 };
 
 use rand::{rngs::OsRng, thread_rng};
 
-use bellperson::groth16::{verify_proofs_batch, PreparedVerifyingKey};
+use bellperson::groth16::{self, verify_proofs_batch, PreparedVerifyingKey};
 use blake2b_simd::Params as Blake2b;
 use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use group::GroupEncoding;
+use ironfish_frost::frost::{keys::PublicKeyPackage, round2::Randomizer, RandomizedParams};
 use jubjub::ExtendedPoint;
 
 use ironfish_zkp::{
@@ -39,8 +41,9 @@ use ironfish_zkp::{
 };
 
 use std::{
+    collections::HashMap,
     io::{self, Write},
-    iter,
+    iter, mem,
     slice::Iter,
 };
 
@@ -66,11 +69,13 @@ mod tests;
 pub use version::TransactionVersion;
 
 const SIGNATURE_HASH_PERSONALIZATION: &[u8; 8] = b"IFsighsh";
+const NULLIFIER_SET_HASH_PERSONALIZATION: &[u8; 8] = b"IFnulset";
 const TRANSACTION_SIGNATURE_VERSION: &[u8; 1] = &[0];
 pub const TRANSACTION_SIGNATURE_SIZE: usize = 64;
 pub const TRANSACTION_PUBLIC_KEY_SIZE: usize = 32;
 pub const TRANSACTION_EXPIRATION_SIZE: usize = 4;
 pub const TRANSACTION_FEE_SIZE: usize = 8;
+pub const PROOF_BUNDLE_HEADER_SIZE: usize = 6 * 4;
 
 /// A collection of spend and output proofs that can be signed and verified.
 /// In general, all the spent values should add up to all the output values.
@@ -112,11 +117,28 @@ pub struct ProposedTransaction {
     /// not expire.
     expiration: u32,
 
+    /// The note commitment tree size of the anchor this transaction is being
+    /// built against, if one was chosen. Spends must use witnesses taken at or
+    /// before this tree size.
+    anchor_tree_size: Option<u32>,
+
+    /// The note commitment tree root this transaction is being built against,
+    /// if one was chosen. Spends must use witnesses to this root.
+    anchor_root: Option<Scalar>,
+
+    /// The maximum number of memo bytes outputs may use, if limited.
+    max_memo_bytes: Option<usize>,
+
     // randomness used for the transaction to calculate the randomized ak, which
     // allows us to verify the sender address is valid and stored in the notes
     // Used to add randomness to signature generation without leaking the
     // key. Referred to as `ar` in the literature.
     public_key_randomness: jubjub::Fr,
+
+    /// Set once the secrets of this transaction have been cleared. With zero
+    /// public_key_randomness the randomized public key would equal the
+    /// authorizing key, so a zeroized transaction must never be built.
+    zeroized: bool,
     // NOTE: If adding fields here, you may need to add fields to
     // signature hash method, and also to Transaction.
 }
@@ -131,7 +153,11 @@ impl ProposedTransaction {
             burns: vec![],
             value_balances: ValueBalances::new(),
             expiration: 0,
+            anchor_tree_size: None,
+            anchor_root: None,
+            max_memo_bytes: None,
             public_key_randomness: jubjub::Fr::random(thread_rng()),
+            zeroized: false,
         }
     }
 
@@ -141,6 +167,8 @@ impl ProposedTransaction {
         note: Note,
         witness: &dyn WitnessTrait,
     ) -> Result<(), IronfishError> {
+        self.check_anchor(witness.tree_size(), &witness.root_hash())?;
+
         self.value_balances
             .add(note.asset_id(), note.value().try_into()?)?;
 
@@ -152,6 +180,21 @@ impl ProposedTransaction {
     /// Create a proof of a new note owned by the recipient in this
     /// transaction.
     pub fn add_output(&mut self, note: Note) -> Result<(), IronfishError> {
+        if let Some(max_memo_bytes) = self.max_memo_bytes {
+            let memo_bytes = note.memo.used_bytes();
+            if memo_bytes > max_memo_bytes {
+                return Err(IronfishError::new_with_source(
+                    IronfishErrorKind::IllegalValue,
+                    format!(
+                        "Output {} has a {} byte memo, exceeding the maximum of {} bytes",
+                        self.outputs.len(),
+                        memo_bytes,
+                        max_memo_bytes
+                    ),
+                ));
+            }
+        }
+
         self.value_balances
             .subtract(note.asset_id(), note.value().try_into()?)?;
 
@@ -160,6 +203,27 @@ impl ProposedTransaction {
         Ok(())
     }
 
+    /// Add zero value native asset outputs to `address` until the transaction
+    /// has `target_output_count` outputs, so that its output count does not
+    /// reveal how many real recipients it has. `address` should be the
+    /// spender's own address, so the decoys only ever pay the spender.
+    ///
+    /// Decoys do not move any value, but each one adds an output description
+    /// to the transaction, raising its size and therefore the fee needed at a
+    /// given fee rate. Change notes are added after the decoys when posting,
+    /// so the final output count can exceed the target.
+    pub fn add_decoy_outputs(
+        &mut self,
+        address: PublicAddress,
+        target_output_count: usize,
+    ) -> Result<(), IronfishError> {
+        while self.outputs.len() < target_output_count {
+            self.add_output(Note::new(address, 0, "", NATIVE_ASSET, address))?;
+        }
+
+        Ok(())
+    }
+
     pub fn add_mint(&mut self, asset: Asset, value: u64) -> Result<(), IronfishError> {
         self.value_balances.add(asset.id(), value.try_into()?)?;
 
@@ -191,6 +255,106 @@ impl ProposedTransaction {
         Ok(())
     }
 
+    /// Whether the two proposed transactions have the same version,
+    /// expiration, spends, outputs, mints, and burns, in the same order.
+    /// Randomness chosen while building, such as value commitment and output
+    /// note randomness, is ignored.
+    pub fn equivalent(&self, other: &ProposedTransaction) -> bool {
+        fn notes_equivalent(a: &Note, b: &Note) -> bool {
+            a.owner == b.owner
+                && a.value == b.value
+                && a.asset_id == b.asset_id
+                && a.memo == b.memo
+                && a.sender == b.sender
+        }
+
+        self.version == other.version
+            && self.expiration == other.expiration
+            && self.spends.len() == other.spends.len()
+            && self.spends.iter().zip(&other.spends).all(|(a, b)| {
+                notes_equivalent(&a.note, &b.note)
+                    && a.note.randomness == b.note.randomness
+                    && a.root_hash == b.root_hash
+                    && a.tree_size == b.tree_size
+                    && a.witness_position == b.witness_position
+            })
+            && self.outputs.len() == other.outputs.len()
+            && self
+                .outputs
+                .iter()
+                .zip(&other.outputs)
+                .all(|(a, b)| notes_equivalent(&a.note, &b.note))
+            && self.mints.len() == other.mints.len()
+            && self.mints.iter().zip(&other.mints).all(|(a, b)| {
+                a.asset == b.asset
+                    && a.value == b.value
+                    && a.transfer_ownership_to == b.transfer_ownership_to
+            })
+            && self.burns.len() == other.burns.len()
+            && self
+                .burns
+                .iter()
+                .zip(&other.burns)
+                .all(|(a, b)| a.asset_id == b.asset_id && a.value == b.value)
+    }
+
+    /// Return an error naming the first output that has a zero value, if any.
+    /// Not enforced by [`ProposedTransaction::post`] since zero value outputs
+    /// are valid, but available to callers that want to reject them.
+    pub fn check_no_zero_value_outputs(&self) -> Result<(), IronfishError> {
+        match self
+            .outputs
+            .iter()
+            .position(|output| output.note.value == 0)
+        {
+            Some(index) => Err(IronfishError::new_with_source(
+                IronfishErrorKind::IllegalValue,
+                format!("Output {} has a zero value", index),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// Return the balance of every asset that cannot be balanced after paying
+    /// `intended_transaction_fee`, i.e. the assets for which posting would fail
+    /// with [`IronfishErrorKind::InvalidBalance`]. Balances are negative,
+    /// giving the deficit of each asset. Surpluses are not reported since they
+    /// are returned to the sender as change.
+    pub fn unbalanced_assets(&self, intended_transaction_fee: i64) -> Vec<(AssetIdentifier, i64)> {
+        self.value_balances
+            .iter()
+            .map(|(asset_id, value)| match asset_id == &NATIVE_ASSET {
+                true => (*asset_id, value.saturating_sub(intended_transaction_fee)),
+                false => (*asset_id, *value),
+            })
+            .filter(|(_, balance)| *balance < 0)
+            .collect()
+    }
+
+    /// For every burned asset, whether the total burned exceeds the total
+    /// value of that asset in the spends. Minted value is not counted, so
+    /// this only flags burns that cannot be covered by the spent notes.
+    pub fn burn_exceeds_spends(&self) -> Vec<(AssetIdentifier, bool)> {
+        let mut burned: HashMap<AssetIdentifier, u128> = HashMap::new();
+        for burn in &self.burns {
+            *burned.entry(burn.asset_id).or_insert(0) += burn.value as u128;
+        }
+
+        burned
+            .into_iter()
+            .map(|(asset_id, burned_value)| {
+                let spent_value: u128 = self
+                    .spends
+                    .iter()
+                    .filter(|spend| spend.note.asset_id() == &asset_id)
+                    .map(|spend| spend.note.value() as u128)
+                    .sum();
+
+                (asset_id, burned_value > spent_value)
+            })
+            .collect()
+    }
+
     fn add_change_notes(
         &mut self,
         change_goes_to: Option<PublicAddress>,
@@ -200,26 +364,13 @@ impl ProposedTransaction {
         let mut change_notes = vec![];
 
         for (asset_id, value) in self.value_balances.iter() {
-            let is_native_asset = asset_id == &NATIVE_ASSET;
-
-            let change_amount = match is_native_asset {
-                true => *value - intended_transaction_fee,
-                false => *value,
-            };
-
-            if change_amount < 0 {
-                return Err(IronfishError::new(IronfishErrorKind::InvalidBalance));
-            }
-            if change_amount > 0 {
-                let change_address = change_goes_to.unwrap_or(public_address);
-                let change_note = Note::new(
-                    change_address,
-                    change_amount as u64, // we checked it was positive
-                    "",
-                    *asset_id,
-                    public_address,
-                );
-
+            if let Some(change_note) = build_change_note(
+                asset_id,
+                *value,
+                change_goes_to,
+                public_address,
+                intended_transaction_fee,
+            )? {
                 change_notes.push(change_note);
             }
         }
@@ -229,6 +380,37 @@ impl ProposedTransaction {
         Ok(())
     }
 
+    /// Construct the change note that posting would create for the given
+    /// asset, or `None` if the asset has no change. The note has the same
+    /// owner, value, asset, and sender as the one posting creates, but its
+    /// randomness (and so its commitment) will differ.
+    pub fn change_note(
+        &self,
+        asset_id: &AssetIdentifier,
+        change_goes_to: Option<PublicAddress>,
+        public_address: PublicAddress,
+        intended_transaction_fee: i64,
+    ) -> Result<Option<Note>, IronfishError> {
+        let value = self
+            .value_balances
+            .iter()
+            .find(|(id, _)| *id == asset_id)
+            .map_or(0, |(_, value)| *value);
+
+        build_change_note(
+            asset_id,
+            value,
+            change_goes_to,
+            public_address,
+            intended_transaction_fee,
+        )
+    }
+
+    /// Build the proofs and binding signature for this transaction, without
+    /// signing the spends and mints.
+    ///
+    /// Outputs follow the same ordering as [`ProposedTransaction::post`]: user
+    /// outputs in submission order, followed by any change notes.
     pub fn build(
         &mut self,
         proof_generation_key: ProofGenerationKey,
@@ -238,6 +420,13 @@ impl ProposedTransaction {
         intended_transaction_fee: i64,
         change_goes_to: Option<PublicAddress>,
     ) -> Result<UnsignedTransaction, IronfishError> {
+        if self.zeroized {
+            return Err(IronfishError::new_with_source(
+                IronfishErrorKind::IllegalValue,
+                "Cannot build a transaction that has been zeroized",
+            ));
+        }
+
         // skip adding change notes if this is special case of a miners fee transaction
         let is_miners_fee = self.outputs.iter().any(|output| output.get_is_miners_fee());
         if !is_miners_fee {
@@ -327,6 +516,11 @@ impl ProposedTransaction {
     ///
     /// sum(spends) + sum(mints) - sum(outputs) - sum(burns) - intended_transaction_fee - change = 0
     /// aka: self.value_balance - intended_transaction_fee - change = 0
+    ///
+    /// Outputs keep the order in which they were added, so the index of an
+    /// output in the posted transaction matches the order of the calls to
+    /// [`ProposedTransaction::add_output`]. Change notes are always appended
+    /// after every user-supplied output.
     pub fn post(
         &mut self,
         spender_key: &SaplingKey,
@@ -348,6 +542,28 @@ impl ProposedTransaction {
         unsigned.sign(spender_key)
     }
 
+    /// Post the transaction like [`ProposedTransaction::post`], also returning
+    /// the index of the output holding the native asset change, or `None` if
+    /// no native asset change was created.
+    pub fn post_tracked(
+        &mut self,
+        spender_key: &SaplingKey,
+        change_goes_to: Option<PublicAddress>,
+        intended_transaction_fee: u64,
+    ) -> Result<(Transaction, Option<usize>), IronfishError> {
+        let user_outputs = self.outputs.len();
+
+        let transaction = self.post(spender_key, change_goes_to, intended_transaction_fee)?;
+
+        // Change notes are appended after the user outputs when building
+        let change_index = self.outputs[user_outputs..]
+            .iter()
+            .position(|output| output.note.asset_id == NATIVE_ASSET)
+            .map(|index| user_outputs + index);
+
+        Ok((transaction, change_index))
+    }
+
     /// Special case for posting a miners fee transaction. Miner fee transactions
     /// are unique in that they generate currency. They do not have any spends
     /// or change and therefore have a negative transaction fee. In normal use,
@@ -369,6 +585,24 @@ impl ProposedTransaction {
         self.post_miners_fee_unchecked(spender_key)
     }
 
+    /// Post a miners fee transaction that pays out the given reward note. This
+    /// is useful for producing reproducible fixtures, since the note (and its
+    /// randomness) is chosen by the caller. The note must be for the native
+    /// asset, and the transaction must not have any other descriptions.
+    pub fn post_miners_fee_with_note(
+        &mut self,
+        note: Note,
+        spender_key: &SaplingKey,
+    ) -> Result<Transaction, IronfishError> {
+        if note.asset_id() != &NATIVE_ASSET || !self.outputs.is_empty() {
+            return Err(IronfishError::new(
+                IronfishErrorKind::InvalidMinersFeeTransaction,
+            ));
+        }
+        self.add_output(note)?;
+        self.post_miners_fee(spender_key)
+    }
+
     /// Do not call this directly -- see post_miners_fee.
     pub fn post_miners_fee_unchecked(
         &mut self,
@@ -394,11 +628,153 @@ impl ProposedTransaction {
         self.expiration
     }
 
+    /// Overwrite the note plaintext and commitment randomness held by the
+    /// pending spends and outputs, then drop every pending description.
+    ///
+    /// The transaction is unusable after this is called and should be
+    /// discarded; building or posting it returns an error.
+    pub fn zeroize(&mut self) {
+        self.zeroized = true;
+
+        for spend in self.spends.iter_mut() {
+            spend.note.zeroize();
+            volatile_overwrite(&mut spend.value_commitment.value, 0);
+            volatile_overwrite(&mut spend.value_commitment.randomness, jubjub::Fr::zero());
+            volatile_overwrite(&mut spend.witness_position, 0);
+            for node in spend.auth_path.iter_mut() {
+                volatile_overwrite(node, None);
+            }
+            spend.auth_path.clear();
+        }
+
+        for output in self.outputs.iter_mut() {
+            output.note.zeroize();
+            volatile_overwrite(&mut output.value_commitment.value, 0);
+            volatile_overwrite(&mut output.value_commitment.randomness, jubjub::Fr::zero());
+        }
+
+        self.spends.clear();
+        self.outputs.clear();
+        self.mints.clear();
+        self.burns.clear();
+        self.value_balances = ValueBalances::new();
+        volatile_overwrite(&mut self.public_key_randomness, jubjub::Fr::zero());
+    }
+
+    /// Choose the note commitment tree size of the anchor this transaction is
+    /// built against. Spends, including those already added, must use a
+    /// witness whose tree size is no larger than the anchor's. If an existing
+    /// spend doesn't, the anchor is left unchanged.
+    pub fn set_anchor_tree_size(&mut self, tree_size: u32) -> Result<(), IronfishError> {
+        for spend in self.spends.iter() {
+            Self::check_witness_against_anchor(
+                Some(tree_size),
+                self.anchor_root,
+                spend.tree_size,
+                &spend.root_hash,
+            )?;
+        }
+
+        self.anchor_tree_size = Some(tree_size);
+
+        Ok(())
+    }
+
+    /// Choose the note commitment tree root this transaction is built against.
+    /// Spends, including those already added, must use a witness to this root.
+    /// If an existing spend doesn't, the anchor is left unchanged.
+    pub fn set_anchor(&mut self, root: Scalar) -> Result<(), IronfishError> {
+        for spend in self.spends.iter() {
+            Self::check_witness_against_anchor(
+                self.anchor_tree_size,
+                Some(root),
+                spend.tree_size,
+                &spend.root_hash,
+            )?;
+        }
+
+        self.anchor_root = Some(root);
+
+        Ok(())
+    }
+
+    fn check_anchor(
+        &self,
+        witness_tree_size: u32,
+        witness_root_hash: &Scalar,
+    ) -> Result<(), IronfishError> {
+        Self::check_witness_against_anchor(
+            self.anchor_tree_size,
+            self.anchor_root,
+            witness_tree_size,
+            witness_root_hash,
+        )
+    }
+
+    fn check_witness_against_anchor(
+        anchor_tree_size: Option<u32>,
+        anchor_root: Option<Scalar>,
+        witness_tree_size: u32,
+        witness_root_hash: &Scalar,
+    ) -> Result<(), IronfishError> {
+        if let Some(anchor_tree_size) = anchor_tree_size {
+            if witness_tree_size > anchor_tree_size {
+                return Err(IronfishError::new_with_source(
+                    IronfishErrorKind::InconsistentWitness,
+                    format!(
+                        "anchor mismatch: witness tree size {} is larger than anchor tree size {}",
+                        witness_tree_size, anchor_tree_size
+                    ),
+                ));
+            }
+        }
+
+        if let Some(anchor_root) = anchor_root {
+            if witness_root_hash != &anchor_root {
+                return Err(IronfishError::new_with_source(
+                    IronfishErrorKind::InconsistentWitness,
+                    "anchor mismatch: witness root hash does not match the anchor root",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Limit the number of memo bytes that outputs added after this call may
+    /// use, out of the fixed size memo field. Trailing zero bytes are padding
+    /// and are not counted.
+    pub fn set_max_memo_bytes(&mut self, max_memo_bytes: usize) {
+        self.max_memo_bytes = Some(max_memo_bytes);
+    }
+
     /// Set the sequence to expire the transaction from the mempool.
     pub fn set_expiration(&mut self, sequence: u32) {
         self.expiration = sequence;
     }
 
+    /// Set the transaction to expire `ttl` blocks after `current_sequence`.
+    /// Fails rather than wrapping if the expiration sequence would overflow.
+    pub fn set_expiration_ttl(
+        &mut self,
+        current_sequence: u32,
+        ttl: u32,
+    ) -> Result<(), IronfishError> {
+        let expiration = current_sequence.checked_add(ttl).ok_or_else(|| {
+            IronfishError::new_with_source(
+                IronfishErrorKind::IllegalValue,
+                format!(
+                    "Expiration overflows: sequence {} plus ttl {}",
+                    current_sequence, ttl
+                ),
+            )
+        })?;
+
+        self.set_expiration(expiration);
+
+        Ok(())
+    }
+
     /// Calculate a hash of the transaction data. This hash is what gets signed
     /// by the private keys to verify that the transaction actually happened.
     ///
@@ -589,22 +965,24 @@ impl Transaction {
         let expiration = reader.read_u32::<LittleEndian>()?;
         let randomized_public_key = redjubjub::PublicKey::read(&mut reader)?;
 
-        let mut spends = Vec::with_capacity(num_spends as usize);
+        // The counts are untrusted, so don't reserve capacity for them up
+        // front; the vectors only grow as descriptions are actually read
+        let mut spends = Vec::new();
         for _ in 0..num_spends {
             spends.push(SpendDescription::read(&mut reader)?);
         }
 
-        let mut outputs = Vec::with_capacity(num_outputs as usize);
+        let mut outputs = Vec::new();
         for _ in 0..num_outputs {
             outputs.push(OutputDescription::read(&mut reader)?);
         }
 
-        let mut mints = Vec::with_capacity(num_mints as usize);
+        let mut mints = Vec::new();
         for _ in 0..num_mints {
             mints.push(MintDescription::read(&mut reader, version)?);
         }
 
-        let mut burns = Vec::with_capacity(num_burns as usize);
+        let mut burns = Vec::new();
         for _ in 0..num_burns {
             burns.push(BurnDescription::read(&mut reader)?);
         }
@@ -624,6 +1002,18 @@ impl Transaction {
         })
     }
 
+    /// Read only the expiration sequence of a serialized transaction, without
+    /// deserializing any of its descriptions.
+    pub fn read_expiration<R: io::Read>(mut reader: R) -> Result<u32, IronfishError> {
+        TransactionVersion::read(&mut reader)?;
+
+        // Skip the spend, output, mint, and burn counts, and the fee
+        let mut skipped = [0; 4 * 8 + TRANSACTION_FEE_SIZE];
+        reader.read_exact(&mut skipped)?;
+
+        Ok(reader.read_u32::<LittleEndian>()?)
+    }
+
     /// Store the bytes of this transaction in the given writer. This is used
     /// to serialize transactions to file or network
     pub fn write<W: io::Write>(&self, mut writer: W) -> Result<(), IronfishError> {
@@ -657,6 +1047,111 @@ impl Transaction {
         Ok(())
     }
 
+    /// Store a compact record of this transaction's public metadata, without
+    /// proofs, encrypted notes, or signatures. The record cannot be read back
+    /// into a transaction or verified; it is meant for archival indexes.
+    ///
+    /// All integers are little-endian. The record holds the version byte, the
+    /// fee (i64), and the expiration (u32), followed by four sections. Each
+    /// section starts with a u32 count of its entries:
+    ///
+    /// - spends: nullifier (32 bytes), tree size (u32), root hash (32 bytes)
+    /// - outputs: note commitment (32 bytes)
+    /// - mints: asset identifier (32 bytes), value (u64)
+    /// - burns: asset identifier (32 bytes), value (u64)
+    pub fn write_metadata<W: io::Write>(&self, mut writer: W) -> Result<(), IronfishError> {
+        self.version.write(&mut writer)?;
+        writer.write_i64::<LittleEndian>(self.fee)?;
+        writer.write_u32::<LittleEndian>(self.expiration)?;
+
+        writer.write_u32::<LittleEndian>(self.spends.len().try_into()?)?;
+        for spend in self.spends.iter() {
+            writer.write_all(&spend.nullifier().0)?;
+            writer.write_u32::<LittleEndian>(spend.tree_size())?;
+            writer.write_all(&spend.root_hash().to_bytes_le())?;
+        }
+
+        writer.write_u32::<LittleEndian>(self.outputs.len().try_into()?)?;
+        for output in self.outputs.iter() {
+            output.merkle_note().merkle_hash().write(&mut writer)?;
+        }
+
+        writer.write_u32::<LittleEndian>(self.mints.len().try_into()?)?;
+        for mint in self.mints.iter() {
+            mint.asset.id().write(&mut writer)?;
+            writer.write_u64::<LittleEndian>(mint.value)?;
+        }
+
+        writer.write_u32::<LittleEndian>(self.burns.len().try_into()?)?;
+        for burn in self.burns.iter() {
+            burn.asset_id.write(&mut writer)?;
+            writer.write_u64::<LittleEndian>(burn.value)?;
+        }
+
+        Ok(())
+    }
+
+    /// Lay out every proof in this transaction along with its public inputs in
+    /// one contiguous buffer, for verifiers that process proofs in bulk.
+    ///
+    /// The buffer starts with a header of six little-endian `u32`s: the number
+    /// of spends, outputs, and mints, followed by the byte offsets of the
+    /// spend, output, and mint sections. Each section holds all of its proofs
+    /// (192 bytes each), followed by all of their public inputs as 32 byte
+    /// little-endian scalars: 7 per spend, 7 per output, and 4 per mint.
+    pub fn proof_bundle(&self) -> Result<Vec<u8>, IronfishError> {
+        let spend_section = write_proof_bundle_section(
+            self.spends
+                .iter()
+                .map(|spend| {
+                    (
+                        &spend.proof,
+                        spend.public_inputs(&self.randomized_public_key),
+                    )
+                })
+                .collect(),
+        )?;
+        let output_section = write_proof_bundle_section(
+            self.outputs
+                .iter()
+                .map(|output| {
+                    (
+                        &output.proof,
+                        output.public_inputs(&self.randomized_public_key),
+                    )
+                })
+                .collect(),
+        )?;
+        let mint_section = write_proof_bundle_section(
+            self.mints
+                .iter()
+                .map(|mint| (&mint.proof, mint.public_inputs(&self.randomized_public_key)))
+                .collect(),
+        )?;
+
+        let spend_offset = PROOF_BUNDLE_HEADER_SIZE;
+        let output_offset = spend_offset + spend_section.len();
+        let mint_offset = output_offset + output_section.len();
+
+        let mut bundle = Vec::with_capacity(mint_offset + mint_section.len());
+        bundle.write_u32::<LittleEndian>(u32::try_from(self.spends.len())?)?;
+        bundle.write_u32::<LittleEndian>(u32::try_from(self.outputs.len())?)?;
+        bundle.write_u32::<LittleEndian>(u32::try_from(self.mints.len())?)?;
+        bundle.write_u32::<LittleEndian>(u32::try_from(spend_offset)?)?;
+        bundle.write_u32::<LittleEndian>(u32::try_from(output_offset)?)?;
+        bundle.write_u32::<LittleEndian>(u32::try_from(mint_offset)?)?;
+        bundle.extend_from_slice(&spend_section);
+        bundle.extend_from_slice(&output_section);
+        bundle.extend_from_slice(&mint_section);
+
+        Ok(bundle)
+    }
+
+    /// Get the serialization version of this transaction
+    pub fn version(&self) -> TransactionVersion {
+        self.version
+    }
+
     /// Get an iterator over the spends in this transaction. Each spend
     /// is by reference
     pub fn iter_spends(&self) -> Iter<SpendDescription> {
@@ -701,6 +1196,17 @@ impl Transaction {
         self.expiration
     }
 
+    /// Approximate number of bytes this transaction occupies in memory,
+    /// including the proofs and descriptions it owns. Useful for sizing
+    /// caches of parsed transactions.
+    pub fn memory_footprint(&self) -> usize {
+        mem::size_of::<Self>()
+            + self.spends.capacity() * mem::size_of::<SpendDescription>()
+            + self.outputs.capacity() * mem::size_of::<OutputDescription>()
+            + self.mints.capacity() * mem::size_of::<MintDescription>()
+            + self.burns.capacity() * mem::size_of::<BurnDescription>()
+    }
+
     /// Get the expiration sequence for this transaction
     pub fn randomized_public_key(&self) -> &redjubjub::PublicKey {
         &self.randomized_public_key
@@ -714,31 +1220,85 @@ impl Transaction {
             .hash_length(32)
             .personal(SIGNATURE_HASH_PERSONALIZATION)
             .to_state();
-        hasher.update(TRANSACTION_SIGNATURE_VERSION);
-        self.version.write(&mut hasher)?;
-        hasher.write_u32::<LittleEndian>(self.expiration)?;
-        hasher.write_i64::<LittleEndian>(self.fee)?;
-        hasher.write_all(&self.randomized_public_key.0.to_bytes())?;
+        self.write_signature_hash_preimage(&mut hasher)?;
+
+        let mut hash_result = [0; 32];
+        hash_result[..].clone_from_slice(hasher.finalize().as_ref());
+        Ok(hash_result)
+    }
+
+    /// A heuristic score from 0 to 100 of how little this transaction reveals
+    /// from its public shape: the number of spends and outputs, and whether it
+    /// mints or burns.
+    pub fn privacy_score(&self) -> u8 {
+        score_privacy(
+            self.spends.len(),
+            self.outputs.len(),
+            !self.mints.is_empty() || !self.burns.is_empty(),
+        )
+    }
+
+    /// Calculate a hash over the sorted nullifiers of this transaction's
+    /// spends. Transactions spending the same set of notes have the same hash,
+    /// regardless of spend order or any other contents.
+    pub fn nullifier_set_hash(&self) -> [u8; 32] {
+        let mut nullifiers: Vec<[u8; 32]> = self
+            .spends
+            .iter()
+            .map(|spend| spend.nullifier().0)
+            .collect();
+        nullifiers.sort_unstable();
+
+        let mut hasher = Blake2b::new()
+            .hash_length(32)
+            .personal(NULLIFIER_SET_HASH_PERSONALIZATION)
+            .to_state();
+        for nullifier in nullifiers.iter() {
+            hasher.update(nullifier);
+        }
+
+        let mut hash_result = [0; 32];
+        hash_result[..].clone_from_slice(hasher.finalize().as_ref());
+        hash_result
+    }
+
+    /// Get the exact bytes that are hashed to produce
+    /// [`Transaction::transaction_signature_hash`]. The hash is a 32 byte
+    /// Blake2b digest of these bytes, personalized with `IFsighsh`.
+    pub fn signature_hash_preimage(&self) -> Result<Vec<u8>, IronfishError> {
+        let mut preimage = vec![];
+        self.write_signature_hash_preimage(&mut preimage)?;
+        Ok(preimage)
+    }
+
+    /// Write the transaction fields that make up the signature hash preimage.
+    fn write_signature_hash_preimage<W: io::Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<(), IronfishError> {
+        writer.write_all(TRANSACTION_SIGNATURE_VERSION)?;
+        self.version.write(&mut writer)?;
+        writer.write_u32::<LittleEndian>(self.expiration)?;
+        writer.write_i64::<LittleEndian>(self.fee)?;
+        writer.write_all(&self.randomized_public_key.0.to_bytes())?;
 
         for spend in self.spends.iter() {
-            spend.serialize_signature_fields(&mut hasher)?;
+            spend.serialize_signature_fields(&mut writer)?;
         }
 
         for output in self.outputs.iter() {
-            output.serialize_signature_fields(&mut hasher)?;
+            output.serialize_signature_fields(&mut writer)?;
         }
 
         for mint in self.mints.iter() {
-            mint.serialize_signature_fields(&mut hasher, self.version)?;
+            mint.serialize_signature_fields(&mut writer, self.version)?;
         }
 
         for burn in self.burns.iter() {
-            burn.serialize_signature_fields(&mut hasher)?;
+            burn.serialize_signature_fields(&mut writer)?;
         }
 
-        let mut hash_result = [0; 32];
-        hash_result[..].clone_from_slice(hasher.finalize().as_ref());
-        Ok(hash_result)
+        Ok(())
     }
 
     /// Confirm that this transaction was signed by the values it contains.
@@ -750,11 +1310,22 @@ impl Transaction {
         let value_balance =
             calculate_value_balance(binding_verification_key, self.fee, &self.mints, &self.burns)?;
 
+        self.verify_binding_signature_with_key(&redjubjub::PublicKey(value_balance))
+    }
+
+    /// Confirm that the binding signature of this transaction is valid under
+    /// the given verifying key, rather than the key derived from the
+    /// transaction's value commitments. This allows auditing a signature
+    /// against a key that was computed independently.
+    pub fn verify_binding_signature_with_key(
+        &self,
+        verifying_key: &redjubjub::PublicKey,
+    ) -> Result<(), IronfishError> {
         let mut data_to_verify_signature = [0; 64];
-        data_to_verify_signature[..32].copy_from_slice(&value_balance.to_bytes());
+        data_to_verify_signature[..32].copy_from_slice(&verifying_key.0.to_bytes());
         data_to_verify_signature[32..].copy_from_slice(&self.transaction_signature_hash()?);
 
-        if !redjubjub::PublicKey(value_balance).verify(
+        if !verifying_key.verify(
             &data_to_verify_signature,
             &self.binding_signature,
             *VALUE_COMMITMENT_RANDOMNESS_GENERATOR,
@@ -764,6 +1335,110 @@ impl Transaction {
 
         Ok(())
     }
+
+    /// Confirm that the signatures on a transaction signed with FROST are
+    /// valid for the group. The group verifying key is randomized with the
+    /// randomizer used for signing and must match the transaction's randomized
+    /// public key, then the signatures are checked as in
+    /// [`verify_transaction_signatures`]. Proofs are not verified.
+    pub fn verify_frost_signature(
+        &self,
+        public_key_package: &PublicKeyPackage,
+        randomizer: Randomizer,
+    ) -> Result<(), IronfishError> {
+        let randomized_params =
+            RandomizedParams::from_randomizer(public_key_package.verifying_key(), randomizer);
+
+        if randomized_params
+            .randomized_verifying_key()
+            .serialize()
+            .as_ref()
+            != self.randomized_public_key.0.to_bytes().as_ref()
+        {
+            return Err(IronfishError::new(IronfishErrorKind::InvalidSignature));
+        }
+
+        verify_transaction_signatures(self)
+    }
+}
+
+/// Heuristic privacy score from 0 to 100, computed only from the publicly
+/// visible shape of a transaction:
+///
+/// - Change concealment, up to 40: with at least two outputs, an observer
+///   cannot tell which output (if any) is change.
+/// - Decoy usage, up to 30: 10 for each output beyond the second, which
+///   suggests padding with decoy outputs.
+/// - Uniformity, up to 30: transactions with at most two spends look like
+///   most others. Each additional spend takes away 10.
+///
+/// Mints and burns publish their asset and value, so 20 is taken away when a
+/// transaction has any.
+fn score_privacy(spends: usize, outputs: usize, has_mints_or_burns: bool) -> u8 {
+    let change_concealment = if outputs >= 2 { 40 } else { 0 };
+    let decoy_usage = outputs.saturating_sub(2).min(3) * 10;
+    let uniformity = 30usize.saturating_sub(spends.saturating_sub(2) * 10);
+
+    let score = change_concealment + decoy_usage + uniformity;
+    let score = match has_mints_or_burns {
+        true => score.saturating_sub(20),
+        false => score,
+    };
+
+    score as u8
+}
+
+/// Construct the change note for an asset with the given value balance, or
+/// `None` if there is no change to return.
+fn build_change_note(
+    asset_id: &AssetIdentifier,
+    value: i64,
+    change_goes_to: Option<PublicAddress>,
+    public_address: PublicAddress,
+    intended_transaction_fee: i64,
+) -> Result<Option<Note>, IronfishError> {
+    let is_native_asset = asset_id == &NATIVE_ASSET;
+
+    let change_amount = match is_native_asset {
+        true => value - intended_transaction_fee,
+        false => value,
+    };
+
+    if change_amount < 0 {
+        return Err(IronfishError::new(IronfishErrorKind::InvalidBalance));
+    }
+    if change_amount == 0 {
+        return Ok(None);
+    }
+
+    let change_address = change_goes_to.unwrap_or(public_address);
+    Ok(Some(Note::new(
+        change_address,
+        change_amount as u64, // we checked it was positive
+        "",
+        *asset_id,
+        public_address,
+    )))
+}
+
+/// Write one section of a [`Transaction::proof_bundle`]: every proof in the
+/// section, followed by every public input.
+fn write_proof_bundle_section<const N: usize>(
+    descriptions: Vec<(&groth16::Proof<Bls12>, [Scalar; N])>,
+) -> Result<Vec<u8>, IronfishError> {
+    let mut section = vec![];
+
+    for (proof, _) in descriptions.iter() {
+        proof.write(&mut section)?;
+    }
+
+    for (_, public_inputs) in descriptions.iter() {
+        for public_input in public_inputs {
+            section.write_all(&public_input.to_bytes_le())?;
+        }
+    }
+
+    Ok(section)
 }
 
 /// Convert the integer value to a point on the Jubjub curve, accounting for
@@ -824,6 +1499,67 @@ fn internal_batch_verify_transactions<'a>(
     spend_verifying_key: &PreparedVerifyingKey<Bls12>,
     output_verifying_key: &PreparedVerifyingKey<Bls12>,
     mint_verifying_key: &PreparedVerifyingKey<Bls12>,
+) -> Result<(), IronfishError> {
+    let transactions: Vec<&Transaction> = transactions.into_iter().collect();
+
+    for transaction in transactions.iter() {
+        verify_transaction_signatures(transaction)?;
+    }
+
+    internal_batch_verify_transaction_proofs(
+        transactions,
+        spend_verifying_key,
+        output_verifying_key,
+        mint_verifying_key,
+    )
+}
+
+/// Validate everything about the transaction except its proofs. Confirms that
+/// the public inputs of each description are well formed, that the spends and
+/// mints were signed by the owner, and that the transaction was signed with a
+/// binding signature. Combined with [`batch_verify_transaction_proofs`] this
+/// is equivalent to [`verify_transaction`].
+pub fn verify_transaction_signatures(transaction: &Transaction) -> Result<(), IronfishError> {
+    // Context to accumulate a signature of all the spends and outputs and
+    // guarantee they are part of this transaction, unmodified.
+    let mut binding_verification_key = ExtendedPoint::identity();
+
+    let hash_to_verify_signature = transaction.transaction_signature_hash()?;
+
+    for spend in transaction.spends.iter() {
+        spend.partial_verify()?;
+
+        binding_verification_key += spend.value_commitment;
+
+        spend.verify_signature(
+            &hash_to_verify_signature,
+            transaction.randomized_public_key(),
+        )?;
+    }
+
+    for output in transaction.outputs.iter() {
+        output.partial_verify()?;
+
+        binding_verification_key -= output.merkle_note.value_commitment;
+    }
+
+    for mint in transaction.mints.iter() {
+        mint.partial_verify()?;
+
+        mint.verify_signature(
+            &hash_to_verify_signature,
+            transaction.randomized_public_key(),
+        )?;
+    }
+
+    transaction.verify_binding_signature(&binding_verification_key)
+}
+
+fn internal_batch_verify_transaction_proofs<'a>(
+    transactions: impl IntoIterator<Item = &'a Transaction>,
+    spend_verifying_key: &PreparedVerifyingKey<Bls12>,
+    output_verifying_key: &PreparedVerifyingKey<Bls12>,
+    mint_verifying_key: &PreparedVerifyingKey<Bls12>,
 ) -> Result<(), IronfishError> {
     let mut spend_proofs = vec![];
     let mut spend_public_inputs = vec![];
@@ -835,59 +1571,31 @@ fn internal_batch_verify_transactions<'a>(
     let mut mint_public_inputs = vec![];
 
     for transaction in transactions {
-        // Context to accumulate a signature of all the spends and outputs and
-        // guarantee they are part of this transaction, unmodified.
-        let mut binding_verification_key = ExtendedPoint::identity();
-
-        let hash_to_verify_signature = transaction.transaction_signature_hash()?;
-
         for spend in transaction.spends.iter() {
-            spend.partial_verify()?;
-
             spend_proofs.push(&spend.proof);
             spend_public_inputs.push(
                 spend
                     .public_inputs(transaction.randomized_public_key())
                     .to_vec(),
             );
-
-            binding_verification_key += spend.value_commitment;
-
-            spend.verify_signature(
-                &hash_to_verify_signature,
-                transaction.randomized_public_key(),
-            )?;
         }
 
         for output in transaction.outputs.iter() {
-            output.partial_verify()?;
-
             output_proofs.push(&output.proof);
             output_public_inputs.push(
                 output
                     .public_inputs(transaction.randomized_public_key())
                     .to_vec(),
             );
-
-            binding_verification_key -= output.merkle_note.value_commitment;
         }
 
         for mint in transaction.mints.iter() {
-            mint.partial_verify()?;
-
             mint_proofs.push(&mint.proof);
             mint_public_inputs.push(
                 mint.public_inputs(transaction.randomized_public_key())
                     .to_vec(),
             );
-
-            mint.verify_signature(
-                &hash_to_verify_signature,
-                transaction.randomized_public_key(),
-            )?;
         }
-
-        transaction.verify_binding_signature(&binding_verification_key)?;
     }
 
     if !spend_proofs.is_empty()
@@ -936,10 +1644,17 @@ fn internal_batch_verify_transactions<'a>(
 pub fn batch_verify_transactions<'a>(
     transactions: impl IntoIterator<Item = &'a Transaction>,
 ) -> Result<(), IronfishError> {
-    internal_batch_verify_transactions(
-        transactions,
-        &SAPLING.spend_verifying_key,
-        &SAPLING.output_verifying_key,
-        &SAPLING.mint_verifying_key,
-    )
+    with_verifying_keys(|spend, output, mint| {
+        internal_batch_verify_transactions(transactions, spend, output, mint)
+    })
+}
+
+/// Batch verify only the spend, output and mint proofs of the transactions.
+/// Signatures are not checked; see [`verify_transaction_signatures`].
+pub fn batch_verify_transaction_proofs<'a>(
+    transactions: impl IntoIterator<Item = &'a Transaction>,
+) -> Result<(), IronfishError> {
+    with_verifying_keys(|spend, output, mint| {
+        internal_batch_verify_transaction_proofs(transactions, spend, output, mint)
+    })
 }
@@ -79,6 +79,12 @@ impl OutputBuilder {
     ///
     /// Verifies the proof before returning to prevent posting broken
     /// transactions.
+    ///
+    /// The proof cannot be generated ahead of time or elsewhere and attached
+    /// to the note afterwards. Its public inputs include the ephemeral key,
+    /// which is generated here, and the transaction's randomized public key,
+    /// which is only chosen when the transaction is built. A proof made
+    /// without both would not verify against this output.
     pub(crate) fn build(
         &self,
         proof_generation_key: &ProofGenerationKey,
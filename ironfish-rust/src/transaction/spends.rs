@@ -194,6 +194,12 @@ impl UnsignedSpendDescription {
         Ok(self.description)
     }
 
+    /// The spend description this will become once signed, with a placeholder
+    /// signature.
+    pub fn description(&self) -> &SpendDescription {
+        &self.description
+    }
+
     pub fn add_signature(mut self, signature: Signature) -> SpendDescription {
         self.description.authorizing_signature = signature;
         self.description
@@ -6,7 +6,10 @@ use std::collections::BTreeMap;
 
 #[cfg(test)]
 use super::internal_batch_verify_transactions;
-use super::{ProposedTransaction, Transaction};
+use super::{
+    calculate_value_balance, score_privacy, ProposedTransaction, Transaction,
+    SIGNATURE_HASH_PERSONALIZATION,
+};
 use crate::frost_utils::{round_one::round_one, round_two::round_two};
 use crate::transaction::tests::split_spender_key::split_spender_key;
 use crate::{
@@ -19,20 +22,25 @@ use crate::{
     sapling_bls12::SAPLING,
     test_util::make_fake_witness,
     transaction::{
-        batch_verify_transactions, verify_transaction, TransactionVersion,
-        TRANSACTION_EXPIRATION_SIZE, TRANSACTION_FEE_SIZE, TRANSACTION_SIGNATURE_SIZE,
+        batch_verify_transaction_proofs, batch_verify_transactions, outputs::PROOF_SIZE,
+        verify_transaction, verify_transaction_signatures, TransactionVersion,
+        PROOF_BUNDLE_HEADER_SIZE, TRANSACTION_EXPIRATION_SIZE, TRANSACTION_FEE_SIZE,
+        TRANSACTION_SIGNATURE_SIZE,
     },
 };
 
+use blake2b_simd::Params as Blake2b;
+use blstrs::Scalar;
 use ff::Field;
 use ironfish_frost::frost::round2::{Randomizer, SignatureShare};
-use ironfish_frost::frost::Identifier;
+use ironfish_frost::frost::{Identifier, SigningPackage};
 use ironfish_frost::participant::Secret;
 use ironfish_zkp::{
     constants::{ASSET_ID_LENGTH, SPENDING_KEY_GENERATOR, TREE_DEPTH},
     proofs::{MintAsset, Output, Spend},
     redjubjub::{self, Signature},
 };
+use jubjub::ExtendedPoint;
 use rand::thread_rng;
 
 #[test]
@@ -103,6 +111,10 @@ fn test_transaction() {
         .post(&spender_key, None, 1)
         .expect("should be able to post transaction");
     verify_transaction(&public_transaction).expect("Should be able to verify transaction");
+    verify_transaction_signatures(&public_transaction)
+        .expect("Should be able to verify transaction signatures");
+    batch_verify_transaction_proofs([&public_transaction])
+        .expect("Should be able to verify transaction proofs");
     assert_eq!(public_transaction.fee(), 1);
 
     // 4 outputs:
@@ -204,6 +216,16 @@ fn test_transaction_simple() {
         .decrypt_note_for_owner(&spender_key_clone.incoming_viewing_key)
         .unwrap();
     assert_eq!(received_note.sender, spender_key_clone.public_address());
+
+    let preimage = unsigned_transaction.signature_hash_preimage().unwrap();
+    let hash = Blake2b::new()
+        .hash_length(32)
+        .personal(SIGNATURE_HASH_PERSONALIZATION)
+        .hash(&preimage);
+    assert_eq!(
+        hash.as_bytes(),
+        unsigned_transaction.transaction_signature_hash().unwrap()
+    );
 }
 
 #[test]
@@ -288,6 +310,53 @@ fn test_miners_fee() {
             .note_encryption_keys,
         NOTE_ENCRYPTION_MINER_KEYS
     );
+
+    let mut metadata = vec![];
+    posted_transaction.write_metadata(&mut metadata).unwrap();
+    // version, fee, expiration, four section counts, and one output commitment
+    assert_eq!(metadata.len(), 1 + 8 + 4 + 4 * 4 + 32);
+    assert_eq!(metadata[1..9], (-42i64).to_le_bytes());
+}
+
+#[test]
+fn test_miners_fee_with_note() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+    let reward_note = Note::new(
+        receiver_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    let posted_transaction = transaction
+        .post_miners_fee_with_note(reward_note.clone(), &spender_key)
+        .expect("it is a valid miner's fee");
+    verify_transaction(&posted_transaction).expect("should be able to verify transaction");
+    assert_eq!(posted_transaction.fee, -42);
+
+    let received_note = posted_transaction.outputs[0]
+        .merkle_note()
+        .decrypt_note_for_owner(receiver_key.incoming_view_key())
+        .expect("should be able to decrypt reward note");
+    assert_eq!(received_note.commitment(), reward_note.commitment());
+
+    // Reward notes must use the native asset
+    let asset = Asset::new(spender_key.public_address(), "testcoin", "").unwrap();
+    let custom_note = Note::new(
+        receiver_key.public_address(),
+        42,
+        "",
+        *asset.id(),
+        spender_key.public_address(),
+    );
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    assert!(matches!(
+        transaction.post_miners_fee_with_note(custom_note, &spender_key),
+        Err(e) if matches!(e.kind, IronfishErrorKind::InvalidMinersFeeTransaction)
+    ));
 }
 
 #[test]
@@ -327,6 +396,18 @@ fn test_transaction_signature() {
     assert_eq!(serialized_signature.len(), TRANSACTION_SIGNATURE_SIZE);
     Signature::read(&mut serialized_signature[..].as_ref())
         .expect("Can deserialize back into a valid Signature");
+
+    let preimage = public_transaction
+        .signature_hash_preimage()
+        .expect("should be able to serialize signature hash preimage");
+    let hash = Blake2b::new()
+        .hash_length(32)
+        .personal(SIGNATURE_HASH_PERSONALIZATION)
+        .hash(&preimage);
+    assert_eq!(
+        hash.as_bytes(),
+        &public_transaction.transaction_signature_hash().unwrap()[..]
+    );
 }
 
 #[test]
@@ -798,9 +879,34 @@ fn test_sign_frost() {
 
     // coordinator creates signing package
     let signing_package = unsigned_transaction
-        .signing_package(commitments)
+        .signing_package(commitments.clone())
         .expect("should be able to create signing package");
 
+    // the package only depends on the commitments, not on the order they were
+    // collected in, so any coordinator can reproduce it byte for byte
+    let mut reversed_commitments = BTreeMap::new();
+    for (identifier, commitment) in commitments.iter().rev() {
+        reversed_commitments.insert(*identifier, commitment.clone());
+    }
+    for reproduced_commitments in [commitments.clone(), reversed_commitments] {
+        let reproduced_package = unsigned_transaction
+            .signing_package(reproduced_commitments)
+            .expect("should be able to create signing package");
+        assert_eq!(
+            reproduced_package.serialize().unwrap(),
+            signing_package.serialize().unwrap()
+        );
+    }
+
+    // participants check the package is for the transaction they were shown
+    assert!(unsigned_transaction
+        .matches_signing_package(&signing_package)
+        .unwrap());
+    let other_package = SigningPackage::new(commitments, &[0; 32]);
+    assert!(!unsigned_transaction
+        .matches_signing_package(&other_package)
+        .unwrap());
+
     // simulate round 2
     let mut signing_shares: BTreeMap<Identifier, SignatureShare> = BTreeMap::new();
     let randomizer =
@@ -818,6 +924,35 @@ fn test_sign_frost() {
         signing_shares.insert(*key_package.0, signature_share);
     }
 
+    // shares must come from exactly the signers in the signing package
+    let mut missing_share = signing_shares.clone();
+    missing_share.pop_first();
+    let err = unsigned_transaction
+        .sign_frost(
+            &key_packages.public_key_package,
+            &signing_package,
+            missing_share,
+        )
+        .expect_err("should not sign without a share from every signer");
+    assert_eq!(err.kind, IronfishErrorKind::InsufficientSignatureShares);
+
+    let mut unknown_share = signing_shares.clone();
+    let (_, share) = unknown_share.pop_first().unwrap();
+    unknown_share.insert(
+        Secret::random(thread_rng())
+            .to_identity()
+            .to_frost_identifier(),
+        share,
+    );
+    let err = unsigned_transaction
+        .sign_frost(
+            &key_packages.public_key_package,
+            &signing_package,
+            unknown_share,
+        )
+        .expect_err("should not sign with a share from outside the signing package");
+    assert_eq!(err.kind, IronfishErrorKind::IllegalValue);
+
     // coordinator creates signed transaction
     let signed_transaction = unsigned_transaction
         .sign_frost(
@@ -834,4 +969,672 @@ fn test_sign_frost() {
 
     // verify transaction
     verify_transaction(&signed_transaction).expect("should be able to verify transaction");
+
+    // the signature is valid for the group only under the same randomness
+    signed_transaction
+        .verify_frost_signature(&key_packages.public_key_package, randomizer)
+        .expect("should be able to verify frost signature");
+    let other_randomizer = Randomizer::deserialize(&jubjub::Fr::random(thread_rng()).to_bytes())
+        .expect("should be able to deserialize randomizer");
+    assert!(signed_transaction
+        .verify_frost_signature(&key_packages.public_key_package, other_randomizer)
+        .is_err());
+}
+
+#[test]
+fn test_zeroize() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+
+    let in_note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let out_note = Note::new(
+        receiver_key.public_address(),
+        40,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note, &witness).unwrap();
+    transaction.add_output(out_note).unwrap();
+
+    transaction.zeroize();
+
+    assert!(transaction.spends.is_empty());
+    assert!(transaction.outputs.is_empty());
+    assert_eq!(*transaction.value_balances.fee(), 0);
+    assert_eq!(transaction.public_key_randomness, jubjub::Fr::zero());
+
+    // posting must fail rather than sign with zero key randomness
+    let err = transaction
+        .post(&spender_key, None, 0)
+        .expect_err("should not post a zeroized transaction");
+    assert_eq!(err.kind, IronfishErrorKind::IllegalValue);
+
+    let err = transaction
+        .post_miners_fee_unchecked(&spender_key)
+        .expect_err("should not post a zeroized miners fee transaction");
+    assert_eq!(err.kind, IronfishErrorKind::IllegalValue);
+}
+
+#[test]
+fn test_post_preserves_output_order() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_keys = [
+        SaplingKey::generate_key(),
+        SaplingKey::generate_key(),
+        SaplingKey::generate_key(),
+    ];
+
+    let in_note = Note::new(
+        spender_key.public_address(),
+        100,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note, &witness).unwrap();
+    for (value, receiver_key) in (10..).zip(receiver_keys.iter()) {
+        let out_note = Note::new(
+            receiver_key.public_address(),
+            value,
+            "",
+            NATIVE_ASSET,
+            spender_key.public_address(),
+        );
+        transaction.add_output(out_note).unwrap();
+    }
+
+    let public_transaction = transaction
+        .post(&spender_key, None, 1)
+        .expect("should be able to post transaction");
+
+    // 3 user outputs followed by 1 change note
+    assert_eq!(public_transaction.outputs.len(), 4);
+
+    for (index, receiver_key) in receiver_keys.iter().enumerate() {
+        let note = public_transaction.outputs[index]
+            .merkle_note()
+            .decrypt_note_for_owner(receiver_key.incoming_view_key())
+            .expect("output should be owned by the receiver at the same index");
+        assert_eq!(note.value(), 10 + index as u64);
+    }
+
+    let change_note = public_transaction.outputs[3]
+        .merkle_note()
+        .decrypt_note_for_owner(spender_key.incoming_view_key())
+        .expect("change should be the last output");
+    assert_eq!(change_note.value(), 100 - 10 - 11 - 12 - 1);
+}
+
+#[test]
+fn test_check_no_zero_value_outputs() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    for value in [5, 0] {
+        let out_note = Note::new(
+            receiver_key.public_address(),
+            value,
+            "",
+            NATIVE_ASSET,
+            spender_key.public_address(),
+        );
+        transaction.add_output(out_note).unwrap();
+    }
+
+    let err = transaction
+        .check_no_zero_value_outputs()
+        .expect_err("should reject the zero value output");
+    assert_eq!(err.kind, IronfishErrorKind::IllegalValue);
+    assert!(err.to_string().contains("Output 1 has a zero value"));
+
+    transaction.outputs.pop();
+    transaction
+        .check_no_zero_value_outputs()
+        .expect("should accept non-zero outputs");
+}
+
+#[test]
+fn test_proof_bundle() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+
+    let in_note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let out_note = Note::new(
+        receiver_key.public_address(),
+        40,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+    let asset = Asset::new(spender_key.public_address(), "testcoin", "").unwrap();
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note, &witness).unwrap();
+    transaction.add_output(out_note).unwrap();
+    transaction.add_mint(asset, 5).unwrap();
+
+    let public_transaction = transaction
+        .post(&spender_key, None, 1)
+        .expect("should be able to post transaction");
+
+    let bundle = public_transaction
+        .proof_bundle()
+        .expect("should be able to export proof bundle");
+
+    let header: Vec<usize> = bundle[..PROOF_BUNDLE_HEADER_SIZE]
+        .chunks(4)
+        .map(|chunk| u32::from_le_bytes(chunk.try_into().unwrap()) as usize)
+        .collect();
+
+    // 1 spend, 3 outputs (1 provided, 1 native change, 1 minted change), 1 mint
+    assert_eq!(header[..3], [1, 3, 1]);
+
+    let proof_size = PROOF_SIZE as usize;
+    let spend_section = proof_size + 7 * 32;
+    let output_section = 3 * (proof_size + 7 * 32);
+    let mint_section = proof_size + 4 * 32;
+    assert_eq!(header[3], PROOF_BUNDLE_HEADER_SIZE);
+    assert_eq!(header[4], header[3] + spend_section);
+    assert_eq!(header[5], header[4] + output_section);
+    assert_eq!(bundle.len(), header[5] + mint_section);
+
+    let mut spend_proof = vec![];
+    public_transaction.spends[0]
+        .proof
+        .write(&mut spend_proof)
+        .unwrap();
+    assert_eq!(&bundle[header[3]..header[3] + proof_size], &spend_proof[..]);
+}
+
+#[test]
+fn test_read_expiration() {
+    let spender_key = SaplingKey::generate_key();
+    let note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(note, &witness).unwrap();
+    transaction.set_expiration(1337);
+
+    let public_transaction = transaction
+        .post(&spender_key, None, 1)
+        .expect("should be able to post transaction");
+
+    let mut serialized = vec![];
+    public_transaction.write(&mut serialized).unwrap();
+
+    assert_eq!(
+        Transaction::read_expiration(&serialized[..]).unwrap(),
+        public_transaction.expiration()
+    );
+    assert_eq!(Transaction::read_expiration(&serialized[..]).unwrap(), 1337);
+}
+
+#[test]
+fn test_read_oversized_count() {
+    let spender_key = SaplingKey::generate_key();
+    let out_note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_output(out_note).unwrap();
+    let posted_transaction = transaction
+        .post_miners_fee(&spender_key)
+        .expect("it is a valid miner's fee");
+
+    let mut serialized = vec![];
+    posted_transaction.write(&mut serialized).unwrap();
+
+    // The spend count follows the version byte
+    serialized[1..9].copy_from_slice(&u64::MAX.to_le_bytes());
+    assert!(Transaction::read(&serialized[..]).is_err());
+}
+
+#[test]
+fn test_verify_binding_signature_with_key() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+
+    let in_note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let out_note = Note::new(
+        receiver_key.public_address(),
+        40,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note, &witness).unwrap();
+    transaction.add_output(out_note).unwrap();
+
+    let public_transaction = transaction
+        .post(&spender_key, None, 1)
+        .expect("should be able to post transaction");
+
+    let mut binding_verification_key = ExtendedPoint::identity();
+    for spend in public_transaction.spends() {
+        binding_verification_key += spend.value_commitment;
+    }
+    for output in public_transaction.outputs() {
+        binding_verification_key -= output.merkle_note.value_commitment;
+    }
+    let value_balance = calculate_value_balance(
+        &binding_verification_key,
+        public_transaction.fee(),
+        public_transaction.mints(),
+        public_transaction.burns(),
+    )
+    .unwrap();
+
+    public_transaction
+        .verify_binding_signature_with_key(&redjubjub::PublicKey(value_balance))
+        .expect("should verify under the value balance key");
+
+    assert!(public_transaction
+        .verify_binding_signature_with_key(&redjubjub::PublicKey(value_balance + value_balance))
+        .is_err());
+}
+
+#[test]
+fn test_anchor_tree_size() {
+    let spender_key = SaplingKey::generate_key();
+    let note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    // The fake witness has a tree size of 1400
+    let witness = make_fake_witness(&note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.set_anchor_tree_size(1399).unwrap();
+    let err = transaction
+        .add_spend(note.clone(), &witness)
+        .expect_err("should not spend against an older anchor");
+    assert_eq!(err.kind, IronfishErrorKind::InconsistentWitness);
+
+    transaction.set_anchor_tree_size(1400).unwrap();
+    transaction.add_spend(note.clone(), &witness).unwrap();
+
+    let err = transaction
+        .set_anchor_tree_size(1000)
+        .expect_err("should not move the anchor before an existing spend");
+    assert_eq!(err.kind, IronfishErrorKind::InconsistentWitness);
+    assert_eq!(transaction.anchor_tree_size, Some(1400));
+
+    // The rejected anchor must not affect later spends
+    transaction.add_spend(note, &witness).unwrap();
+}
+
+#[test]
+fn test_unbalanced_assets() {
+    let spender_key = SaplingKey::generate_key();
+    let asset = Asset::new(spender_key.public_address(), "testcoin", "").unwrap();
+
+    let in_note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note, &witness).unwrap();
+    transaction.add_mint(asset, 5).unwrap();
+
+    assert!(transaction.unbalanced_assets(42).is_empty());
+    assert_eq!(transaction.unbalanced_assets(50), vec![(NATIVE_ASSET, -8)]);
+
+    transaction.add_burn(*asset.id(), 7).unwrap();
+    let mut unbalanced = transaction.unbalanced_assets(50);
+    unbalanced.sort_by_key(|(_, balance)| *balance);
+    assert_eq!(unbalanced, vec![(NATIVE_ASSET, -8), (*asset.id(), -2)]);
+}
+
+#[test]
+fn test_max_memo_bytes() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+    let short_memo_note = Note::new(
+        receiver_key.public_address(),
+        1,
+        "abcd",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let long_memo_note = Note::new(
+        receiver_key.public_address(),
+        1,
+        "abcde",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_output(long_memo_note.clone()).unwrap();
+
+    transaction.set_max_memo_bytes(4);
+    transaction.add_output(short_memo_note).unwrap();
+    let err = transaction
+        .add_output(long_memo_note)
+        .expect_err("memo should be too long");
+    assert_eq!(err.kind, IronfishErrorKind::IllegalValue);
+    assert_eq!(transaction.outputs.len(), 2);
+}
+
+#[test]
+fn test_nullifier_set_hash() {
+    let spender_key = SaplingKey::generate_key();
+    let note_one = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let note_two = Note::new(
+        spender_key.public_address(),
+        7,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness_one = make_fake_witness(&note_one);
+    let witness_two = make_fake_witness(&note_two);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction
+        .add_spend(note_one.clone(), &witness_one)
+        .unwrap();
+    transaction
+        .add_spend(note_two.clone(), &witness_two)
+        .unwrap();
+    let posted = transaction.post(&spender_key, None, 1).unwrap();
+
+    // Spending the same notes in a different order, with a different fee
+    let mut reordered = ProposedTransaction::new(TransactionVersion::latest());
+    reordered.add_spend(note_two, &witness_two).unwrap();
+    reordered.add_spend(note_one.clone(), &witness_one).unwrap();
+    let posted_reordered = reordered.post(&spender_key, None, 2).unwrap();
+
+    assert_eq!(
+        posted.nullifier_set_hash(),
+        posted_reordered.nullifier_set_hash()
+    );
+
+    let mut subset = ProposedTransaction::new(TransactionVersion::latest());
+    subset.add_spend(note_one, &witness_one).unwrap();
+    let posted_subset = subset.post(&spender_key, None, 1).unwrap();
+
+    assert_ne!(
+        posted.nullifier_set_hash(),
+        posted_subset.nullifier_set_hash()
+    );
+}
+
+#[test]
+fn test_post_tracked() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+    let in_note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let out_note = Note::new(
+        receiver_key.public_address(),
+        40,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note.clone(), &witness).unwrap();
+    transaction.add_output(out_note.clone()).unwrap();
+
+    let (posted, change_index) = transaction.post_tracked(&spender_key, None, 1).unwrap();
+    assert_eq!(change_index, Some(1));
+    let change_note = posted.outputs()[1]
+        .merkle_note()
+        .decrypt_note_for_owner(spender_key.incoming_view_key())
+        .unwrap();
+    assert_eq!(change_note.value(), 1);
+
+    // No change when the fee consumes the remainder
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note, &witness).unwrap();
+    transaction.add_output(out_note).unwrap();
+
+    let (_, change_index) = transaction.post_tracked(&spender_key, None, 2).unwrap();
+    assert_eq!(change_index, None);
+}
+
+#[test]
+fn test_add_decoy_outputs() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+    let out_note = Note::new(
+        receiver_key.public_address(),
+        1,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_output(out_note).unwrap();
+    transaction
+        .add_decoy_outputs(spender_key.public_address(), 4)
+        .unwrap();
+
+    assert_eq!(transaction.outputs.len(), 4);
+    for output in transaction.outputs[1..].iter() {
+        assert_eq!(output.note.value, 0);
+        assert_eq!(output.note.owner, spender_key.public_address());
+    }
+
+    // Already at or above the target
+    transaction
+        .add_decoy_outputs(spender_key.public_address(), 2)
+        .unwrap();
+    assert_eq!(transaction.outputs.len(), 4);
+}
+
+#[test]
+fn test_anchor_root() {
+    let spender_key = SaplingKey::generate_key();
+    let note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction
+        .set_anchor(witness.root_hash + Scalar::one())
+        .unwrap();
+    let err = transaction
+        .add_spend(note.clone(), &witness)
+        .expect_err("should not spend against a different root");
+    assert_eq!(err.kind, IronfishErrorKind::InconsistentWitness);
+
+    transaction.set_anchor(witness.root_hash).unwrap();
+    transaction.add_spend(note.clone(), &witness).unwrap();
+
+    let err = transaction
+        .set_anchor(Scalar::zero())
+        .expect_err("should not move the anchor away from an existing spend");
+    assert_eq!(err.kind, IronfishErrorKind::InconsistentWitness);
+    assert_eq!(transaction.anchor_root, Some(witness.root_hash));
+
+    // The rejected root must not affect later spends
+    transaction.add_spend(note, &witness).unwrap();
+}
+
+#[test]
+fn test_change_note() {
+    let spender_key = SaplingKey::generate_key();
+    let in_note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note, &witness).unwrap();
+
+    let change_note = transaction
+        .change_note(&NATIVE_ASSET, None, spender_key.public_address(), 2)
+        .unwrap()
+        .expect("should have change");
+    assert_eq!(change_note.value(), 40);
+    assert_eq!(change_note.owner(), spender_key.public_address());
+    assert_eq!(change_note.sender(), spender_key.public_address());
+
+    assert!(transaction
+        .change_note(&NATIVE_ASSET, None, spender_key.public_address(), 42)
+        .unwrap()
+        .is_none());
+    assert!(transaction
+        .change_note(&NATIVE_ASSET, None, spender_key.public_address(), 43)
+        .is_err());
+}
+
+#[test]
+fn test_burn_exceeds_spends() {
+    let spender_key = SaplingKey::generate_key();
+    let asset = Asset::new(spender_key.public_address(), "testcoin", "").unwrap();
+
+    let in_note = Note::new(
+        spender_key.public_address(),
+        10,
+        "",
+        *asset.id(),
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+    transaction.add_spend(in_note, &witness).unwrap();
+    assert!(transaction.burn_exceeds_spends().is_empty());
+
+    transaction.add_burn(*asset.id(), 6).unwrap();
+    assert_eq!(
+        transaction.burn_exceeds_spends(),
+        vec![(*asset.id(), false)]
+    );
+
+    transaction.add_burn(*asset.id(), 5).unwrap();
+    assert_eq!(transaction.burn_exceeds_spends(), vec![(*asset.id(), true)]);
+}
+
+#[test]
+fn test_proposed_equivalent() {
+    let spender_key = SaplingKey::generate_key();
+    let receiver_key = SaplingKey::generate_key();
+    let in_note = Note::new(
+        spender_key.public_address(),
+        42,
+        "",
+        NATIVE_ASSET,
+        spender_key.public_address(),
+    );
+    let witness = make_fake_witness(&in_note);
+
+    let build = |value: u64| {
+        let out_note = Note::new(
+            receiver_key.public_address(),
+            value,
+            "",
+            NATIVE_ASSET,
+            spender_key.public_address(),
+        );
+        let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+        transaction.add_spend(in_note.clone(), &witness).unwrap();
+        transaction.add_output(out_note).unwrap();
+        transaction
+    };
+
+    assert!(build(40).equivalent(&build(40)));
+    assert!(!build(40).equivalent(&build(41)));
+
+    let mut expiring = build(40);
+    expiring.set_expiration(10);
+    assert!(!expiring.equivalent(&build(40)));
+}
+
+#[test]
+fn test_set_expiration_ttl() {
+    let mut transaction = ProposedTransaction::new(TransactionVersion::latest());
+
+    transaction.set_expiration_ttl(100, 20).unwrap();
+    assert_eq!(transaction.expiration, 120);
+
+    assert!(transaction.set_expiration_ttl(u32::MAX, 1).is_err());
+    assert_eq!(transaction.expiration, 120);
+}
+
+#[test]
+fn test_score_privacy() {
+    // A single output with no change reveals the full amount moved
+    assert_eq!(score_privacy(1, 1, false), 30);
+    assert_eq!(score_privacy(1, 2, false), 70);
+    assert_eq!(score_privacy(2, 5, false), 100);
+    assert_eq!(score_privacy(2, 10, false), 100);
+    assert_eq!(score_privacy(4, 2, false), 50);
+    assert_eq!(score_privacy(1, 2, true), 50);
+    assert_eq!(score_privacy(10, 0, true), 0);
 }
@@ -6,7 +6,7 @@ use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
 use group::GroupEncoding;
 use ironfish_frost::frost::{
     aggregate,
-    keys::PublicKeyPackage,
+    keys::{KeyPackage, PublicKeyPackage},
     round1::SigningCommitments,
     round2::{Randomizer, SignatureShare},
     Identifier, RandomizedParams, SigningPackage,
@@ -20,6 +20,7 @@ use std::{
 
 use crate::{
     errors::{IronfishError, IronfishErrorKind},
+    frost_utils::{round_one::round_one, round_two::round_two},
     serializing::read_scalar,
     transaction::Blake2b,
     OutputDescription, SaplingKey, Transaction,
@@ -30,6 +31,10 @@ use super::{
     TransactionVersion, SIGNATURE_HASH_PERSONALIZATION, TRANSACTION_SIGNATURE_VERSION,
 };
 
+/// Personalization for mixing the transaction's signature hash into the seed
+/// given to [`UnsignedTransaction::sign_frost_single`].
+const FROST_SINGLE_SEED_PERSONALIZATION: &[u8; 8] = b"IFfrseed";
+
 #[derive(Clone)]
 pub struct UnsignedTransaction {
     /// The transaction serialization version. This can be incremented when
@@ -87,22 +92,24 @@ impl UnsignedTransaction {
         let randomized_public_key = redjubjub::PublicKey::read(&mut reader)?;
         let public_key_randomness = read_scalar(&mut reader)?;
 
-        let mut spends = Vec::with_capacity(num_spends as usize);
+        // The counts are untrusted, so don't reserve capacity for them up
+        // front; the vectors only grow as descriptions are actually read
+        let mut spends = Vec::new();
         for _ in 0..num_spends {
             spends.push(UnsignedSpendDescription::read(&mut reader)?);
         }
 
-        let mut outputs = Vec::with_capacity(num_outputs as usize);
+        let mut outputs = Vec::new();
         for _ in 0..num_outputs {
             outputs.push(OutputDescription::read(&mut reader)?);
         }
 
-        let mut mints = Vec::with_capacity(num_mints as usize);
+        let mut mints = Vec::new();
         for _ in 0..num_mints {
             mints.push(UnsignedMintDescription::read(&mut reader, version)?);
         }
 
-        let mut burns = Vec::with_capacity(num_burns as usize);
+        let mut burns = Vec::new();
         for _ in 0..num_burns {
             burns.push(BurnDescription::read(&mut reader)?);
         }
@@ -165,40 +172,94 @@ impl UnsignedTransaction {
             .hash_length(32)
             .personal(SIGNATURE_HASH_PERSONALIZATION)
             .to_state();
-        hasher.update(TRANSACTION_SIGNATURE_VERSION);
-        self.version.write(&mut hasher)?;
-        hasher.write_u32::<LittleEndian>(self.expiration)?;
-        hasher.write_i64::<LittleEndian>(self.fee)?;
-        hasher.write_all(&self.randomized_public_key.0.to_bytes())?;
+        self.write_signature_hash_preimage(&mut hasher)?;
+
+        let mut hash_result = [0; 32];
+        hash_result[..].clone_from_slice(hasher.finalize().as_ref());
+        Ok(hash_result)
+    }
+
+    /// Get the bytes covered by the signatures of this transaction, excluding
+    /// the signatures themselves. These are hashed to produce
+    /// [`UnsignedTransaction::transaction_signature_hash`].
+    pub fn signature_hash_preimage(&self) -> Result<Vec<u8>, IronfishError> {
+        let mut preimage = vec![];
+        self.write_signature_hash_preimage(&mut preimage)?;
+        Ok(preimage)
+    }
+
+    /// Write the transaction fields that make up the signature hash preimage.
+    fn write_signature_hash_preimage<W: io::Write>(
+        &self,
+        mut writer: W,
+    ) -> Result<(), IronfishError> {
+        writer.write_all(TRANSACTION_SIGNATURE_VERSION)?;
+        self.version.write(&mut writer)?;
+        writer.write_u32::<LittleEndian>(self.expiration)?;
+        writer.write_i64::<LittleEndian>(self.fee)?;
+        writer.write_all(&self.randomized_public_key.0.to_bytes())?;
 
         for spend in self.spends.iter() {
-            spend.description.serialize_signature_fields(&mut hasher)?;
+            spend.description.serialize_signature_fields(&mut writer)?;
         }
 
         for output in self.outputs.iter() {
-            output.serialize_signature_fields(&mut hasher)?;
+            output.serialize_signature_fields(&mut writer)?;
         }
 
         for mint in self.mints.iter() {
             mint.description
-                .serialize_signature_fields(&mut hasher, self.version)?;
+                .serialize_signature_fields(&mut writer, self.version)?;
         }
 
         for burn in self.burns.iter() {
-            burn.serialize_signature_fields(&mut hasher)?;
+            burn.serialize_signature_fields(&mut writer)?;
         }
 
-        let mut hash_result = [0; 32];
-        hash_result[..].clone_from_slice(hasher.finalize().as_ref());
-        Ok(hash_result)
+        Ok(())
     }
 
+    /// Aggregate the signature shares into the authorizing signature and
+    /// produce the signed transaction.
+    ///
+    /// There must be exactly one share from each signer that committed to the
+    /// signing package. This is stricter than requiring exactly the threshold
+    /// number of shares: the signing package fixes the set of signers, and
+    /// every committed nonce is bound into the group commitment, so a package
+    /// with more signers than the threshold needs all of their shares. The
+    /// threshold itself is not recorded in the public key package and can't
+    /// be checked here; a package with fewer committed signers than the
+    /// threshold fails aggregation.
     pub fn sign_frost(
         &mut self,
         public_key_package: &PublicKeyPackage,
         authorizing_signing_package: &SigningPackage,
         authorizing_signature_shares: BTreeMap<Identifier, SignatureShare>,
     ) -> Result<Transaction, IronfishError> {
+        let signing_commitments = authorizing_signing_package.signing_commitments();
+        if let Some(identifier) = authorizing_signature_shares
+            .keys()
+            .find(|identifier| !signing_commitments.contains_key(identifier))
+        {
+            return Err(IronfishError::new_with_source(
+                IronfishErrorKind::IllegalValue,
+                format!(
+                    "Signature share from {:?}, which is not a signer in the signing package",
+                    identifier
+                ),
+            ));
+        }
+        if authorizing_signature_shares.len() != signing_commitments.len() {
+            return Err(IronfishError::new_with_source(
+                IronfishErrorKind::InsufficientSignatureShares,
+                format!(
+                    "Expected signature shares from the {} signers in the signing package, got {}",
+                    signing_commitments.len(),
+                    authorizing_signature_shares.len()
+                ),
+            ));
+        }
+
         // Create the transaction signature hash
         let data_to_sign = self.transaction_signature_hash()?;
 
@@ -254,6 +315,65 @@ impl UnsignedTransaction {
         Ok(transaction)
     }
 
+    /// Sign with the key package of a 1-of-1 FROST account, running both
+    /// rounds of the protocol and aggregating the single share in one step.
+    /// Only valid when the threshold is 1.
+    ///
+    /// **The seed must be fresh randomness for every signature.** The signing
+    /// nonces are derived from it, and signing two different messages with
+    /// the same nonces reveals the key share. As a safeguard the seed is mixed
+    /// with the transaction's signature hash, so the nonces differ between
+    /// transactions, but that is not a substitute for a random seed.
+    pub fn sign_frost_single(
+        &mut self,
+        key_package: &KeyPackage,
+        seed: u64,
+    ) -> Result<Transaction, IronfishError> {
+        if *key_package.min_signers() != 1 {
+            return Err(IronfishError::new_with_source(
+                IronfishErrorKind::IllegalValue,
+                format!(
+                    "Single signer FROST signing requires a threshold of 1, not {}",
+                    key_package.min_signers()
+                ),
+            ));
+        }
+
+        // Round two regenerates the nonces committed to in round one, so both
+        // rounds use the same seed
+        let mut hasher = Blake2b::new()
+            .hash_length(8)
+            .personal(FROST_SINGLE_SEED_PERSONALIZATION)
+            .to_state();
+        hasher.update(&seed.to_le_bytes());
+        hasher.update(&self.transaction_signature_hash()?);
+        let seed = u64::from_le_bytes(hasher.finalize().as_bytes().try_into().unwrap());
+
+        let identifier = *key_package.identifier();
+        let (_, commitments) = round_one(key_package, seed);
+        let signing_package = self.signing_package(BTreeMap::from([(identifier, commitments)]))?;
+
+        let randomizer = Randomizer::deserialize(&self.public_key_randomness.to_bytes())
+            .map_err(|e| IronfishError::new_with_source(IronfishErrorKind::InvalidRandomizer, e))?;
+        let signature_share = round_two(
+            signing_package.clone(),
+            key_package.clone(),
+            randomizer,
+            seed,
+        )?;
+
+        let public_key_package = PublicKeyPackage::new(
+            BTreeMap::from([(identifier, *key_package.verifying_share())]),
+            *key_package.verifying_key(),
+        );
+
+        self.sign_frost(
+            &public_key_package,
+            &signing_package,
+            BTreeMap::from([(identifier, signature_share)]),
+        )
+    }
+
     // Post transaction without much validation.
     pub fn sign(&self, spender_key: &SaplingKey) -> Result<Transaction, IronfishError> {
         // Create the transaction signature hash
@@ -285,7 +405,9 @@ impl UnsignedTransaction {
     }
 
     // Creates frost signing package for use in round two of FROST multisig protocol
-    // only applicable for multisig transactions
+    // only applicable for multisig transactions. The package is deterministic:
+    // commitments are ordered by identifier, so the same commitments always
+    // produce the same package.
     pub fn signing_package(
         &self,
         commitments: BTreeMap<Identifier, SigningCommitments>,
@@ -295,8 +417,43 @@ impl UnsignedTransaction {
         Ok(SigningPackage::new(commitments, &data_to_sign))
     }
 
+    /// Whether the signing package asks signers to sign this transaction's
+    /// signature hash. Participants should check this before producing a
+    /// signature share for a package received from a coordinator.
+    pub fn matches_signing_package(
+        &self,
+        signing_package: &SigningPackage,
+    ) -> Result<bool, IronfishError> {
+        let data_to_sign = self.transaction_signature_hash()?;
+        Ok(signing_package.message()[..] == data_to_sign[..])
+    }
+
     // Exposes the public key package for use in round two of FROST multisig protocol
     pub fn public_key_randomness(&self) -> jubjub::Fr {
         self.public_key_randomness
     }
+
+    pub fn spends(&self) -> &Vec<UnsignedSpendDescription> {
+        &self.spends
+    }
+
+    pub fn outputs(&self) -> &Vec<OutputDescription> {
+        &self.outputs
+    }
+
+    pub fn mints(&self) -> &Vec<UnsignedMintDescription> {
+        &self.mints
+    }
+
+    pub fn burns(&self) -> &Vec<BurnDescription> {
+        &self.burns
+    }
+
+    pub fn fee(&self) -> i64 {
+        self.fee
+    }
+
+    pub fn expiration(&self) -> u32 {
+        self.expiration
+    }
 }
@@ -6,7 +6,7 @@ use blstrs::Bls12;
 
 use crate::{
     errors::{IronfishError, IronfishErrorKind},
-    sapling_bls12::SAPLING,
+    sapling_bls12::with_verifying_keys,
 };
 
 /// Helper function for verifying spend proof internally. Note that this is not
@@ -16,7 +16,8 @@ pub(crate) fn verify_spend_proof(
     proof: &groth16::Proof<Bls12>,
     inputs: &[blstrs::Scalar],
 ) -> Result<(), IronfishError> {
-    if !groth16::verify_proof(&SAPLING.spend_verifying_key, proof, inputs)? {
+    let verified = with_verifying_keys(|spend, _, _| groth16::verify_proof(spend, proof, inputs))?;
+    if !verified {
         return Err(IronfishError::new(IronfishErrorKind::InvalidSpendProof));
     }
 
@@ -30,7 +31,9 @@ pub(crate) fn verify_output_proof(
     proof: &groth16::Proof<Bls12>,
     inputs: &[blstrs::Scalar],
 ) -> Result<(), IronfishError> {
-    if !groth16::verify_proof(&SAPLING.output_verifying_key, proof, inputs)? {
+    let verified =
+        with_verifying_keys(|_, output, _| groth16::verify_proof(output, proof, inputs))?;
+    if !verified {
         return Err(IronfishError::new(IronfishErrorKind::InvalidOutputProof));
     }
 
@@ -44,7 +47,8 @@ pub(crate) fn verify_mint_proof(
     proof: &groth16::Proof<Bls12>,
     inputs: &[blstrs::Scalar],
 ) -> Result<(), IronfishError> {
-    if !groth16::verify_proof(&SAPLING.mint_verifying_key, proof, inputs)? {
+    let verified = with_verifying_keys(|_, _, mint| groth16::verify_proof(mint, proof, inputs))?;
+    if !verified {
         return Err(IronfishError::new(IronfishErrorKind::InvalidMintProof));
     }
 
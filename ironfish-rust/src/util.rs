@@ -2,6 +2,8 @@
  * License, v. 2.0. If a copy of the MPL was not distributed with this
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 use std::cmp;
+use std::ptr;
+use std::sync::atomic::{compiler_fence, Ordering};
 
 /// Helper function to create an array from a string. If the string is not as
 /// large as the array, it will be filled with 0. If the string is too large, it
@@ -16,6 +18,16 @@ pub fn str_to_array<const SIZE: usize>(string: &str) -> [u8; SIZE] {
     arr
 }
 
+/// Overwrite `dst` with `value` using a volatile write followed by a compiler
+/// fence, so that clearing secret values can't be optimized away as a dead
+/// store the way a plain assignment can.
+pub(crate) fn volatile_overwrite<T: Copy>(dst: &mut T, value: T) {
+    // SAFETY: `dst` is a valid, aligned and exclusive reference, and `Copy`
+    // types have no destructor that skipping the old value's drop could miss.
+    unsafe { ptr::write_volatile(dst, value) };
+    compiler_fence(Ordering::SeqCst);
+}
+
 #[cfg(test)]
 mod test {
     use super::str_to_array;
@@ -3,8 +3,10 @@
  * file, You can obtain one at https://mozilla.org/MPL/2.0/. */
 
 use blstrs::Scalar;
+use ironfish_zkp::constants::TREE_DEPTH;
 
 use super::MerkleNoteHash;
+use crate::errors::{IronfishError, IronfishErrorKind};
 use std::fmt::{self, Debug};
 
 /// Witness to a specific node in an authentication path.
@@ -33,6 +35,79 @@ pub trait WitnessTrait {
     fn tree_size(&self) -> u32;
 }
 
+/// The smallest note commitment tree size that includes a note at each of the
+/// given positions. This is the minimum `tree_size` a witness must be taken at
+/// for all of those notes to be spent against the same anchor.
+pub fn required_tree_size(positions: &[u64]) -> Result<u32, IronfishError> {
+    let size = positions
+        .iter()
+        .max()
+        .map_or(0, |position| position.saturating_add(1));
+
+    Ok(u32::try_from(size)?)
+}
+
+/// Compute witnesses for the notes at the given positions of a note commitment
+/// tree holding `leaves`, in position order. The tree levels are hashed once
+/// and shared by all of the witnesses. As in the tree kept by the node, a node
+/// without a right sibling is hashed together with itself.
+pub fn witnesses_for_positions(
+    leaves: &[Scalar],
+    positions: &[u64],
+) -> Result<Vec<Witness>, IronfishError> {
+    let tree_size = leaves.len();
+    if positions
+        .iter()
+        .any(|&position| position >= tree_size as u64)
+    {
+        return Err(IronfishError::new(IronfishErrorKind::InvalidData));
+    }
+
+    let mut levels: Vec<Vec<Scalar>> = Vec::with_capacity(TREE_DEPTH + 1);
+    levels.push(leaves.to_vec());
+    for depth in 0..TREE_DEPTH {
+        let parents = levels[depth]
+            .chunks(2)
+            .map(|pair| {
+                let right = pair.get(1).unwrap_or(&pair[0]);
+                MerkleNoteHash::combine_hash(depth, &pair[0], right)
+            })
+            .collect();
+        levels.push(parents);
+    }
+
+    let root_hash = match levels[TREE_DEPTH].first() {
+        Some(root_hash) => *root_hash,
+        None => return Ok(vec![]),
+    };
+
+    let witnesses = positions
+        .iter()
+        .map(|&position| {
+            let auth_path = levels[..TREE_DEPTH]
+                .iter()
+                .enumerate()
+                .map(|(depth, level)| {
+                    let index = (position >> depth) as usize;
+                    if index % 2 == 0 {
+                        WitnessNode::Left(*level.get(index + 1).unwrap_or(&level[index]))
+                    } else {
+                        WitnessNode::Right(level[index - 1])
+                    }
+                })
+                .collect();
+
+            Witness {
+                tree_size,
+                root_hash,
+                auth_path,
+            }
+        })
+        .collect();
+
+    Ok(witnesses)
+}
+
 /// A Rust implementation of a WitnessTrait, used for testing Witness-related
 /// code within Rust.
 pub struct Witness {
@@ -41,6 +116,61 @@ pub struct Witness {
     pub auth_path: Vec<WitnessNode<Scalar>>,
 }
 
+impl Witness {
+    /// Construct a witness from an auth path packed by
+    /// [`Witness::to_compressed`].
+    pub fn from_compressed(
+        root_hash: Scalar,
+        tree_size: usize,
+        packed: &[u8],
+    ) -> Result<Self, IronfishError> {
+        // Each node takes 32 bytes for its sibling hash plus one bit of side
+        let depth = (0..=packed.len() / 32)
+            .find(|depth| depth * 32 + (depth + 7) / 8 == packed.len())
+            .ok_or_else(|| IronfishError::new(IronfishErrorKind::InvalidData))?;
+
+        let (hashes, sides) = packed.split_at(depth * 32);
+        let mut auth_path = Vec::with_capacity(depth);
+        for (i, hash) in hashes.chunks(32).enumerate() {
+            let hash = MerkleNoteHash::read(hash)?.0;
+            if sides[i / 8] & (1 << (i % 8)) != 0 {
+                auth_path.push(WitnessNode::Left(hash));
+            } else {
+                auth_path.push(WitnessNode::Right(hash));
+            }
+        }
+
+        Ok(Witness {
+            tree_size,
+            root_hash,
+            auth_path,
+        })
+    }
+
+    /// Pack the auth path into a compact binary blob: the 32 byte sibling
+    /// hash of each node, followed by a bitfield with one bit per node that
+    /// is set when the node is a [`WitnessNode::Left`].
+    pub fn to_compressed(&self) -> Vec<u8> {
+        let depth = self.auth_path.len();
+        let mut hashes = Vec::with_capacity(depth * 32 + (depth + 7) / 8);
+        let mut sides = vec![0u8; (depth + 7) / 8];
+
+        for (i, node) in self.auth_path.iter().enumerate() {
+            let hash = match node {
+                WitnessNode::Left(hash) => {
+                    sides[i / 8] |= 1 << (i % 8);
+                    hash
+                }
+                WitnessNode::Right(hash) => hash,
+            };
+            hashes.extend_from_slice(&hash.to_bytes_le());
+        }
+
+        hashes.extend_from_slice(&sides);
+        hashes
+    }
+}
+
 /// Implement partial equality, ignoring the Sapling Arc
 impl PartialEq for Witness {
     fn eq(&self, other: &Witness) -> bool {
@@ -95,3 +225,52 @@ impl fmt::Debug for Witness {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::{witnesses_for_positions, Witness, WitnessNode, WitnessTrait};
+    use crate::{merkle_note::position, MerkleNoteHash};
+    use blstrs::Scalar;
+
+    #[test]
+    fn test_compressed_round_trip() {
+        let auth_path = (0..32)
+            .map(|i| match i % 3 {
+                0 => WitnessNode::Left(Scalar::from(i)),
+                _ => WitnessNode::Right(Scalar::from(i)),
+            })
+            .collect();
+        let witness = Witness {
+            tree_size: 40,
+            root_hash: Scalar::from(99),
+            auth_path,
+        };
+
+        let packed = witness.to_compressed();
+        assert_eq!(packed.len(), 32 * 32 + 4);
+
+        let unpacked = Witness::from_compressed(Scalar::from(99), 40, &packed).unwrap();
+        assert_eq!(unpacked, witness);
+
+        assert!(Witness::from_compressed(Scalar::from(99), 40, &packed[1..]).is_err());
+    }
+
+    #[test]
+    fn test_witnesses_for_positions() {
+        let leaves: Vec<Scalar> = (0..5u64).map(Scalar::from).collect();
+
+        let witnesses = witnesses_for_positions(&leaves, &[0, 3, 4]).unwrap();
+        assert_eq!(witnesses.len(), 3);
+
+        for (witness, pos) in witnesses.iter().zip([0, 3, 4]) {
+            assert_eq!(witness.tree_size, 5);
+            assert_eq!(witness.root_hash, witnesses[0].root_hash);
+            assert_eq!(position(witness), pos);
+            assert!(witness.verify(&MerkleNoteHash(leaves[pos as usize])));
+        }
+
+        assert!(!witnesses[1].verify(&MerkleNoteHash(leaves[0])));
+        assert!(witnesses_for_positions(&leaves, &[5]).is_err());
+        assert!(witnesses_for_positions(&[], &[]).unwrap().is_empty());
+    }
+}